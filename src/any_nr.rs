@@ -0,0 +1,126 @@
+//! Provider of [`AnyNr`].
+
+use crate::prelude::*;
+use crate::{AnyNw, nr_unsize};
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+/// Type-erased strong reference to node.
+///
+/// This type keeps node alive like [`Nr`], but node type is erased.
+/// So, nodes of different types can be stored in the same collection.
+/// Comparison is based on location like [`Nr`].
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::AnyNr;
+/// # use easy_node::prelude::*;
+/// # use std::collections::HashSet;
+/// let nr1 = Nr::new(42);
+/// let nr2 = Nr::new("foo");
+/// let set = HashSet::from([AnyNr::new(nr1.clone()), AnyNr::new(nr2)]);
+/// assert!(set.contains(Nr::key(&nr1)));
+///
+/// let any = set.get(Nr::key(&nr1)).unwrap().clone();
+/// assert_eq!(any.downcast::<i32>().unwrap(), nr1);
+/// ```
+#[derive(Clone)]
+pub struct AnyNr(Nr<dyn Any>);
+
+impl AnyNr {
+    /// Creates a new instance from node pointer.
+    #[must_use]
+    pub fn new<T: Any>(node: Nr<T>) -> Self {
+        Self(nr_unsize!(node, dyn Any))
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        Nr::key(&self.0)
+    }
+
+    /// Returns `true` if the node type is `T`.
+    #[must_use]
+    pub fn is<T: Any>(&self) -> bool {
+        (*self.0).is::<T>()
+    }
+
+    /// Attempts to downcast this pointer to a concrete type.
+    ///
+    /// If the type is not matched, this pointer is returned as [`Err`].
+    pub fn downcast<T: Any>(self) -> std::result::Result<Nr<T>, Self> {
+        self.0.downcast().map_err(Self)
+    }
+
+    /// Creates a new type-erased weak pointer to this node.
+    #[must_use]
+    pub fn downgrade(&self) -> AnyNw {
+        AnyNw::from_parts(Nr::downgrade(&self.0), (*self.0).type_id())
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(&self) -> usize {
+        Nr::strong_count(&self.0)
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(&self) -> usize {
+        Nr::weak_count(&self.0)
+    }
+
+    /// Creates instance from type-erased node pointer.
+    pub(crate) fn from_base(base: Nr<dyn Any>) -> Self {
+        Self(base)
+    }
+}
+
+impl Borrow<NodeKey> for AnyNr {
+    fn borrow(&self) -> &NodeKey {
+        self.key()
+    }
+}
+
+impl Debug for AnyNr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("AnyNr").field(&self.key().addr()).finish()
+    }
+}
+
+impl Eq for AnyNr {}
+
+impl<T: Any> From<Nr<T>> for AnyNr {
+    fn from(value: Nr<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Hash for AnyNr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Ord for AnyNr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(other.key())
+    }
+}
+
+impl PartialEq for AnyNr {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl PartialOrd for AnyNr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
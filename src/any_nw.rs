@@ -0,0 +1,118 @@
+//! Provider of [`AnyNw`].
+
+use crate::prelude::*;
+use crate::{AnyNr, nw_unsize};
+use std::any::{Any, TypeId};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+/// Type-erased weak reference to node.
+///
+/// This type refers node weakly like [`Nw`], but node type is erased.
+/// Unlike [`Nw`] to `dyn Any`, node type is remembered by this pointer.
+/// So, it can be downcast even after the node has been dropped.
+#[derive(Clone)]
+pub struct AnyNw(Nw<dyn Any>, TypeId);
+
+impl AnyNw {
+    /// Creates a new instance from node pointer.
+    #[must_use]
+    pub fn new<T: Any>(node: Nw<T>) -> Self {
+        Self(nw_unsize!(node, dyn Any), TypeId::of::<T>())
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        self.0.key()
+    }
+
+    /// Returns `true` if the node type is `T`.
+    #[must_use]
+    pub fn is<T: Any>(&self) -> bool {
+        self.1 == TypeId::of::<T>()
+    }
+
+    /// Attempts to downcast this pointer to a concrete type.
+    ///
+    /// If the type is not matched, this pointer is returned as [`Err`].
+    pub fn downcast<T: Any>(self) -> std::result::Result<Nw<T>, Self> {
+        if !self.is::<T>() {
+            return Err(self);
+        }
+
+        let ptr = self.0.into_raw().cast::<T>();
+        Ok(unsafe { Nw::from_raw(ptr) })
+    }
+
+    /// Attempts to upgrade to type-erased strong pointer.
+    ///
+    /// Returns [`None`] if the node has since been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<AnyNr> {
+        self.0.upgrade().map(AnyNr::from_base)
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(&self) -> usize {
+        self.0.strong_count()
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(&self) -> usize {
+        self.0.weak_count()
+    }
+
+    /// Creates instance from type-erased node pointer and its type.
+    pub(crate) fn from_parts(base: Nw<dyn Any>, type_id: TypeId) -> Self {
+        Self(base, type_id)
+    }
+}
+
+impl Borrow<NodeKey> for AnyNw {
+    fn borrow(&self) -> &NodeKey {
+        self.key()
+    }
+}
+
+impl Debug for AnyNw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("AnyNw").field(&self.key().addr()).finish()
+    }
+}
+
+impl Eq for AnyNw {}
+
+impl<T: Any> From<Nw<T>> for AnyNw {
+    fn from(value: Nw<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Hash for AnyNw {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Ord for AnyNw {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(other.key())
+    }
+}
+
+impl PartialEq for AnyNw {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl PartialOrd for AnyNw {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
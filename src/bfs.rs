@@ -0,0 +1,55 @@
+//! Provider of [`Bfs`].
+
+use crate::prelude::*;
+use crate::util::*;
+use std::collections::{HashSet, VecDeque};
+
+/// Breadth-first traversal of nodes.
+///
+/// Each node is visited only once (based on node identity).
+/// Weak links to dropped nodes are skipped.
+#[derive(Debug)]
+pub struct Bfs<T: ?Sized> {
+    queue: VecDeque<Nr<T>>,
+    visited: HashSet<Nr<T>>,
+}
+
+impl<T: Neighbors + ?Sized> Bfs<T> {
+    /// Creates a new instance starting from the root.
+    #[must_use]
+    pub fn new(root: &Nr<T>) -> Self {
+        Self::from_roots([root.clone()])
+    }
+
+    /// Creates a new instance starting from the roots.
+    #[must_use]
+    pub fn from_roots<I>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        for root in roots {
+            if visited.insert(root.clone()) {
+                queue.push_back(root);
+            }
+        }
+
+        Self { queue, visited }
+    }
+}
+
+impl<T: Neighbors + ?Sized> Iterator for Bfs<T> {
+    type Item = Nr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for neighbor in live_neighbors(&node) {
+            if self.visited.insert(neighbor.clone()) {
+                self.queue.push_back(neighbor);
+            }
+        }
+
+        Some(node)
+    }
+}
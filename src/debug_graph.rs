@@ -0,0 +1,100 @@
+//! Provider of [`DebugGraph`].
+
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt::{Debug, Formatter, Result};
+
+thread_local! {
+    static LABELS: RefCell<Option<HashMap<*const (), usize>>> = const { RefCell::new(None) };
+}
+
+/// Cycle-aware debug formatter of node graph.
+///
+/// While formatting with this type, each node is labeled like `#0` on
+/// first visit, and repeat visits are printed only as the label. So, even
+/// cyclic structures can be printed safely.
+///
+/// This type is created by `debug_graph` method of node pointers.
+pub struct DebugGraph<'a, P: ?Sized>(&'a P);
+
+impl<'a, P: ?Sized> DebugGraph<'a, P> {
+    pub(crate) fn new(target: &'a P) -> Self {
+        Self(target)
+    }
+}
+
+impl<P: Debug + ?Sized> Debug for DebugGraph<'_, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let _scope = Scope::enter();
+        self.0.fmt(f)
+    }
+}
+
+/// Label of node in graph formatting.
+pub(crate) enum Label {
+    /// Label of first visited node.
+    First(usize),
+    /// Label of already visited node.
+    Repeat(usize),
+}
+
+impl Label {
+    /// Returns the label of the node if graph formatting is active.
+    pub(crate) fn get(key: &NodeKey) -> Option<Self> {
+        LABELS.with_borrow_mut(|labels| {
+            let labels = labels.as_mut()?;
+            let next = labels.len();
+            Some(match labels.entry(key.addr()) {
+                Entry::Occupied(e) => Self::Repeat(*e.get()),
+                Entry::Vacant(e) => Self::First(*e.insert(next)),
+            })
+        })
+    }
+
+    /// Returns `true` if graph formatting is active.
+    pub(crate) fn is_active() -> bool {
+        LABELS.with_borrow(Option::is_some)
+    }
+}
+
+/// Formats node with graph formatting awareness.
+pub(crate) fn fmt_node<T>(name: &str, key: &NodeKey, value: &T, f: &mut Formatter<'_>) -> Result
+where
+    T: Debug + ?Sized,
+{
+    match Label::get(key) {
+        None => f.debug_tuple(name).field(&value).finish(),
+        Some(Label::First(x)) => {
+            write!(f, "#{x} ")?;
+            value.fmt(f)
+        }
+        Some(Label::Repeat(x)) => write!(f, "#{x}"),
+    }
+}
+
+/// Scope of graph formatting.
+struct Scope {
+    is_owner: bool,
+}
+
+impl Scope {
+    fn enter() -> Self {
+        let is_owner = LABELS.with_borrow_mut(|labels| {
+            let is_owner = labels.is_none();
+            labels.get_or_insert_with(HashMap::new);
+            is_owner
+        });
+
+        Self { is_owner }
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        if self.is_owner {
+            LABELS.with_borrow_mut(|labels| *labels = None);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Provider of [`Dfs`].
+
+use crate::prelude::*;
+use crate::util::*;
+use std::collections::HashSet;
+
+/// Depth-first traversal of nodes in pre-order.
+///
+/// Each node is visited only once (based on node identity).
+/// Weak links to dropped nodes are skipped.
+#[derive(Debug)]
+pub struct Dfs<T: ?Sized> {
+    stack: Vec<Nr<T>>,
+    visited: HashSet<Nr<T>>,
+}
+
+impl<T: Neighbors + ?Sized> Dfs<T> {
+    /// Creates a new instance starting from the root.
+    #[must_use]
+    pub fn new(root: &Nr<T>) -> Self {
+        Self::from_roots([root.clone()])
+    }
+
+    /// Creates a new instance starting from the roots.
+    #[must_use]
+    pub fn from_roots<I>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let mut stack = roots.into_iter().collect::<Vec<_>>();
+        stack.reverse();
+        let visited = HashSet::new();
+        Self { stack, visited }
+    }
+}
+
+impl<T: Neighbors + ?Sized> Iterator for Dfs<T> {
+    type Item = Nr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.stack.pop()?;
+            if !self.visited.insert(node.clone()) {
+                continue;
+            }
+
+            let top = self.stack.len();
+            self.stack.extend(live_neighbors(&node));
+            self.stack[top..].reverse();
+            return Some(node);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Provider of [`DfsPostOrder`].
+
+use crate::prelude::*;
+use crate::util::*;
+use std::collections::HashSet;
+use std::vec::IntoIter;
+
+/// Depth-first traversal of nodes in post-order.
+///
+/// Each node is visited only once (based on node identity).
+/// Weak links to dropped nodes are skipped.
+#[derive(Debug)]
+pub struct DfsPostOrder<T: ?Sized> {
+    roots: IntoIter<Nr<T>>,
+    stack: Vec<(Nr<T>, IntoIter<Nr<T>>)>,
+    visited: HashSet<Nr<T>>,
+}
+
+impl<T: Neighbors + ?Sized> DfsPostOrder<T> {
+    /// Creates a new instance starting from the root.
+    #[must_use]
+    pub fn new(root: &Nr<T>) -> Self {
+        Self::from_roots([root.clone()])
+    }
+
+    /// Creates a new instance starting from the roots.
+    #[must_use]
+    pub fn from_roots<I>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let roots = roots.into_iter().collect::<Vec<_>>().into_iter();
+        let stack = Vec::new();
+        let visited = HashSet::new();
+        Self {
+            roots,
+            stack,
+            visited,
+        }
+    }
+
+    fn enter(&mut self, node: Nr<T>) {
+        if self.visited.insert(node.clone()) {
+            let neighbors = live_neighbors(&node).collect::<Vec<_>>();
+            self.stack.push((node, neighbors.into_iter()));
+        }
+    }
+}
+
+impl<T: Neighbors + ?Sized> Iterator for DfsPostOrder<T> {
+    type Item = Nr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((_, neighbors)) = self.stack.last_mut() else {
+                let root = self.roots.next()?;
+                self.enter(root);
+                continue;
+            };
+
+            match neighbors.next() {
+                Some(neighbor) => self.enter(neighbor),
+                None => return self.stack.pop().map(|x| x.0),
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Provider of [`DotWriter`].
+
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{Result, Write};
+
+type NodeLabelFn<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+type EdgeLabelFn<'a, T> = Box<dyn Fn(&T, &T) -> String + 'a>;
+type Edge = (usize, usize, bool);
+
+/// Writer of node graph in Graphviz DOT format.
+///
+/// Nodes reachable from roots are written as vertices (one vertex per
+/// node identity). Strong links are written as solid edges, and weak links
+/// are written as dashed edges. Weak links to dropped nodes are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::DotWriter;
+/// # use easy_node::prelude::*;
+/// struct Node(&'static str, Vec<Nr<Node>>);
+///
+/// impl Neighbors for Node {
+///     fn neighbors(&self) -> impl Iterator<Item = Link<Self>> {
+///         self.1.iter().cloned().map(Link::from)
+///     }
+/// }
+///
+/// let leaf = Nr::new(Node("leaf", vec![]));
+/// let root = Nr::new(Node("root", vec![leaf]));
+/// let dot = DotWriter::new().node_label(|x: &Node| x.0.to_string());
+/// let result = dot.render([root]);
+/// assert!(result.contains(r#"n0 [label="root"];"#));
+/// assert!(result.contains("n0 -> n1;"));
+/// ```
+pub struct DotWriter<'a, T: ?Sized> {
+    node_label: Option<NodeLabelFn<'a, T>>,
+    edge_label: Option<EdgeLabelFn<'a, T>>,
+}
+
+impl<'a, T: Neighbors + ?Sized> DotWriter<'a, T> {
+    /// Creates a new instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            node_label: None,
+            edge_label: None,
+        }
+    }
+
+    /// Sets function to make node labels.
+    #[must_use]
+    pub fn node_label<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T) -> String + 'a,
+    {
+        self.node_label = Some(Box::new(f));
+        self
+    }
+
+    /// Sets function to make edge labels from source and target nodes.
+    #[must_use]
+    pub fn edge_label<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> String + 'a,
+    {
+        self.edge_label = Some(Box::new(f));
+        self
+    }
+
+    /// Returns DOT text of graph reachable from roots.
+    #[must_use]
+    pub fn render<I>(&self, roots: I) -> String
+    where
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let mut ret = String::new();
+        let result = self.write(&mut ret, roots);
+        result.expect("Writing to string never fails.");
+        ret
+    }
+
+    /// Writes DOT text of graph reachable from roots.
+    pub fn write<W, I>(&self, out: &mut W, roots: I) -> Result
+    where
+        W: Write,
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let (nodes, edges) = traverse(roots);
+        writeln!(out, "digraph {{")?;
+        for (id, node) in nodes.iter().enumerate() {
+            write!(out, "    n{id}")?;
+            if let Some(f) = &self.node_label {
+                write!(out, " [label=\"{}\"]", escape(&f(node)))?;
+            }
+            writeln!(out, ";")?;
+        }
+
+        for (source, target, is_weak) in edges {
+            let mut attrs = Vec::new();
+            if is_weak {
+                attrs.push("style=dashed".to_string());
+            }
+            if let Some(f) = &self.edge_label {
+                let label = f(&nodes[source], &nodes[target]);
+                attrs.push(format!("label=\"{}\"", escape(&label)));
+            }
+
+            write!(out, "    n{source} -> n{target}")?;
+            if !attrs.is_empty() {
+                write!(out, " [{}]", attrs.join(", "))?;
+            }
+            writeln!(out, ";")?;
+        }
+
+        writeln!(out, "}}")
+    }
+}
+
+impl<T: Neighbors + ?Sized> Default for DotWriter<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects nodes (in breadth-first order) and edges reachable from roots.
+///
+/// Edges are source index, target index, and weakness of link.
+/// Neighbors of each node are read only once. So, edges always refer to
+/// collected nodes even if links are changed during writing.
+fn traverse<T, I>(roots: I) -> (Vec<Nr<T>>, Vec<Edge>)
+where
+    T: Neighbors + ?Sized,
+    I: IntoIterator<Item = Nr<T>>,
+{
+    let mut nodes = Vec::new();
+    let mut ids = HashMap::new();
+    let mut id_of = |node: Nr<T>, nodes: &mut Vec<Nr<T>>| {
+        *ids.entry(node.clone()).or_insert_with(|| {
+            nodes.push(node);
+            nodes.len() - 1
+        })
+    };
+
+    for root in roots {
+        id_of(root, &mut nodes);
+    }
+
+    let mut edges = Vec::new();
+    let mut source = 0;
+    while source < nodes.len() {
+        for link in nodes[source].neighbors().collect::<Vec<_>>() {
+            let Some(target) = link.upgrade() else {
+                continue;
+            };
+
+            let target = id_of(target, &mut nodes);
+            edges.push((source, target, link.is_weak()));
+        }
+
+        source += 1;
+    }
+
+    (nodes, edges)
+}
+
+/// Escapes text for DOT quoted string.
+fn escape(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            _ => ret.push(c),
+        }
+    }
+
+    ret
+}
@@ -0,0 +1,297 @@
+//! Provider of [`Graph`].
+
+use crate::prelude::*;
+use std::cell::{Cell, Ref, RefCell};
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FusedIterator;
+use std::slice;
+use std::sync::atomic::{self, AtomicU64};
+use std::vec;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Directed graph owning its nodes.
+///
+/// Nodes are [`GraphNode`] nodes held strongly by this graph, and edges
+/// are held weakly by their nodes. Each edge has a payload (use `()` if
+/// payloads are not needed). Removing a node also removes its incoming
+/// and outgoing edges.
+///
+/// Nodes are iterated in insertion order. On dropping this graph, all
+/// nodes are detached. So, node pointers held outside never keep edges.
+///
+/// As [`Neighbors`], [`GraphNode`] returns outgoing edges as weak links.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::Graph;
+/// let mut graph = Graph::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(&a, &b, ());
+/// assert_eq!(graph.neighbors(&a).collect::<Vec<_>>(), [b.clone()]);
+/// graph.remove_node(&b);
+/// assert_eq!(graph.neighbors(&a).count(), 0);
+/// ```
+pub struct Graph<N, E = ()> {
+    id: u64,
+    nodes: Vec<Nr<GraphNode<N, E>>>,
+    edge_count: usize,
+}
+
+impl<N, E> Graph<N, E> {
+    /// Creates a new empty instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            nodes: Vec::new(),
+            edge_count: 0,
+        }
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Returns `true` if this graph contains no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns `true` if the node is an element of this graph.
+    #[must_use]
+    pub fn contains(&self, node: &Nr<GraphNode<N, E>>) -> bool {
+        node.graph.get() == self.id
+    }
+
+    /// Returns an iterator over nodes in insertion order.
+    pub fn nodes(&self) -> slice::Iter<'_, Nr<GraphNode<N, E>>> {
+        self.nodes.iter()
+    }
+
+    /// Adds node with the value, returning it.
+    pub fn add_node(&mut self, value: N) -> Nr<GraphNode<N, E>> {
+        let ret = Nr::new(GraphNode::new(self.id, value));
+        self.nodes.push(ret.clone());
+        ret
+    }
+
+    /// Adds edge between the nodes with the payload.
+    ///
+    /// Returns old payload if the edge already existed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not an element of this graph.
+    pub fn add_edge(
+        &mut self,
+        from: &Nr<GraphNode<N, E>>,
+        to: &Nr<GraphNode<N, E>>,
+        value: E,
+    ) -> Option<E> {
+        let is_member = self.contains(from) && self.contains(to);
+        assert!(is_member, "Nodes must be elements of this graph.");
+        if let Some(edge) = from.out_edges.borrow_mut().iter_mut().find(|x| x.0 == *to) {
+            return Some(std::mem::replace(&mut edge.1, value));
+        }
+
+        from.out_edges.borrow_mut().push((Nr::downgrade(to), value));
+        to.in_edges.borrow_mut().push(Nr::downgrade(from));
+        self.edge_count += 1;
+        None
+    }
+
+    /// Removes edge between the nodes, returning its payload.
+    pub fn remove_edge(
+        &mut self,
+        from: &Nr<GraphNode<N, E>>,
+        to: &Nr<GraphNode<N, E>>,
+    ) -> Option<E> {
+        if !self.contains(from) {
+            return None;
+        }
+
+        let mut out_edges = from.out_edges.borrow_mut();
+        let index = out_edges.iter().position(|x| x.0 == *to)?;
+        let ret = out_edges.remove(index).1;
+        to.in_edges.borrow_mut().retain(|x| *x != *from);
+        self.edge_count -= 1;
+        Some(ret)
+    }
+
+    /// Returns payload of edge between the nodes.
+    #[must_use]
+    pub fn edge<'a>(
+        &self,
+        from: &'a Nr<GraphNode<N, E>>,
+        to: &Nr<GraphNode<N, E>>,
+    ) -> Option<Ref<'a, E>> {
+        let out_edges = from.out_edges.borrow();
+        Ref::filter_map(out_edges, |x| x.iter().find(|x| x.0 == *to).map(|x| &x.1)).ok()
+    }
+
+    /// Removes the node and its edges from this graph.
+    ///
+    /// Returns `true` if the node was an element of this graph.
+    pub fn remove_node(&mut self, node: &Nr<GraphNode<N, E>>) -> bool {
+        if !self.contains(node) {
+            return false;
+        }
+
+        for (target, _) in node.out_edges.take() {
+            let target = target.upgrade().unwrap();
+            target.in_edges.borrow_mut().retain(|x| *x != *node);
+            self.edge_count -= 1;
+        }
+
+        for source in node.in_edges.take() {
+            let source = source.upgrade().unwrap();
+            source.out_edges.borrow_mut().retain(|x| x.0 != *node);
+            self.edge_count -= 1;
+        }
+
+        node.graph.set(0);
+        self.nodes.retain(|x| x != node);
+        true
+    }
+
+    /// Returns an iterator over targets of outgoing edges of the node.
+    ///
+    /// Targets are collected at this call, so the iterator does not keep
+    /// the node borrowed.
+    pub fn neighbors(&self, node: &Nr<GraphNode<N, E>>) -> GraphNeighbors<N, E> {
+        let out_edges = node.out_edges.borrow();
+        let nodes = out_edges.iter().filter_map(|x| x.0.upgrade());
+        GraphNeighbors(nodes.collect::<Vec<_>>().into_iter())
+    }
+
+    /// Returns an iterator over sources of incoming edges of the node.
+    ///
+    /// Sources are collected at this call, so the iterator does not keep
+    /// the node borrowed.
+    pub fn in_neighbors(&self, node: &Nr<GraphNode<N, E>>) -> GraphNeighbors<N, E> {
+        let in_edges = node.in_edges.borrow();
+        let nodes = in_edges.iter().filter_map(Nw::upgrade);
+        GraphNeighbors(nodes.collect::<Vec<_>>().into_iter())
+    }
+
+    /// Removes all nodes and edges.
+    pub fn clear(&mut self) {
+        for node in self.nodes.drain(..) {
+            node.out_edges.take();
+            node.in_edges.take();
+            node.graph.set(0);
+        }
+
+        self.edge_count = 0;
+    }
+}
+
+impl<N: Debug, E> Debug for Graph<N, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.nodes()).finish()
+    }
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> Drop for Graph<N, E> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, N, E> IntoIterator for &'a Graph<N, E> {
+    type Item = &'a Nr<GraphNode<N, E>>;
+    type IntoIter = slice::Iter<'a, Nr<GraphNode<N, E>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes()
+    }
+}
+
+/// Node of [`Graph`].
+pub struct GraphNode<N, E = ()> {
+    graph: Cell<u64>,
+    out_edges: RefCell<Vec<(Nw<Self>, E)>>,
+    in_edges: RefCell<Vec<Nw<Self>>>,
+    value: N,
+}
+
+impl<N, E> GraphNode<N, E> {
+    fn new(graph: u64, value: N) -> Self {
+        Self {
+            graph: Cell::new(graph),
+            out_edges: RefCell::new(Vec::new()),
+            in_edges: RefCell::new(Vec::new()),
+            value,
+        }
+    }
+
+    /// Returns the value of this node.
+    #[must_use]
+    pub fn value(&self) -> &N {
+        &self.value
+    }
+
+    /// Returns `true` if this node is an element of some graph.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.graph.get() != 0
+    }
+}
+
+impl<N: Debug, E> Debug for GraphNode<N, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("GraphNode").field(&self.value).finish()
+    }
+}
+
+impl<N, E> Neighbors for GraphNode<N, E> {
+    fn neighbors(&self) -> impl Iterator<Item = Link<Self>> {
+        let out_edges = self.out_edges.borrow();
+        let links = out_edges.iter().map(|x| Link::Weak(x.0.clone()));
+        links.collect::<Vec<_>>().into_iter()
+    }
+}
+
+/// Iterator over neighbors of [`GraphNode`].
+#[derive(Debug)]
+pub struct GraphNeighbors<N, E>(vec::IntoIter<Nr<GraphNode<N, E>>>);
+
+impl<N, E> Iterator for GraphNeighbors<N, E> {
+    type Item = Nr<GraphNode<N, E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<N, E> DoubleEndedIterator for GraphNeighbors<N, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<N, E> ExactSizeIterator for GraphNeighbors<N, E> {}
+
+impl<N, E> FusedIterator for GraphNeighbors<N, E> {}
@@ -0,0 +1,241 @@
+//! Provider of [`LeakReport`].
+
+use crate::Bfs;
+use crate::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Report of nodes leaked by strong reference cycles.
+///
+/// This type checks whether nodes reachable from roots will be freed once
+/// the roots are dropped. Roots are taken by value, so strong pointers
+/// held outside (including other clones of roots) are treated as external
+/// owners, and nodes kept by them are not leaks.
+///
+/// Nodes whose strong count is fully explained by strong links from other
+/// unowned nodes, and which are kept by strong cycles, are reported as leaks.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::LeakReport;
+/// # use easy_node::prelude::*;
+/// # use std::cell::RefCell;
+/// struct Node(RefCell<Vec<Nr<Node>>>);
+///
+/// impl Neighbors for Node {
+///     fn neighbors(&self) -> impl Iterator<Item = Link<Self>> {
+///         self.0.borrow().clone().into_iter().map(Link::from)
+///     }
+/// }
+///
+/// let node = Nr::new(Node(RefCell::new(vec![])));
+/// node.0.borrow_mut().push(node.clone());
+/// let report = LeakReport::new([node]);
+/// assert_eq!(report.cycles().len(), 1);
+/// # report.leaked()[0].0.borrow_mut().clear();
+/// ```
+#[derive(Debug)]
+pub struct LeakReport<T: ?Sized> {
+    leaked: Vec<Nr<T>>,
+    cycles: Vec<Vec<Nr<T>>>,
+}
+
+impl<T: Neighbors + ?Sized> LeakReport<T> {
+    /// Creates a new report of graph reachable from roots.
+    #[must_use]
+    pub fn new<I>(roots: I) -> Self
+    where
+        I: IntoIterator<Item = Nr<T>>,
+    {
+        let nodes = Bfs::from_roots(roots).collect::<Vec<_>>();
+        let graph = StrongGraph::new(&nodes);
+        let owned = graph.owned(&nodes);
+        let sub = graph.without(&owned);
+        let cycles = (sub.components().into_iter())
+            .filter(|x| x.len() > 1 || sub.edges[x[0]].contains(&x[0]))
+            .map(|x| sub.cycle(&x))
+            .collect::<Vec<_>>();
+
+        let starts = cycles.iter().map(|x| x[0]).collect::<Vec<_>>();
+        let leaked = sub.reach(&starts);
+        let to_nodes = |x: &[usize]| x.iter().map(|&i| nodes[i].clone()).collect();
+        let leaked = (0..nodes.len()).filter(|&i| leaked[i]).collect::<Vec<_>>();
+        Self {
+            leaked: to_nodes(&leaked),
+            cycles: cycles.iter().map(|x| to_nodes(x)).collect(),
+        }
+    }
+}
+
+impl<T: ?Sized> LeakReport<T> {
+    /// Returns `true` if no nodes are leaked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.leaked.is_empty()
+    }
+
+    /// Returns leaked nodes.
+    #[must_use]
+    pub fn leaked(&self) -> &[Nr<T>] {
+        &self.leaked
+    }
+
+    /// Returns strong cycles causing leaks.
+    ///
+    /// Each cycle is a path of nodes, and the last node
+    /// has strong link to the first node.
+    #[must_use]
+    pub fn cycles(&self) -> &[Vec<Nr<T>>] {
+        &self.cycles
+    }
+}
+
+/// Graph of strong links between node indices.
+struct StrongGraph {
+    edges: Vec<Vec<usize>>,
+}
+
+impl StrongGraph {
+    fn new<T: Neighbors + ?Sized>(nodes: &[Nr<T>]) -> Self {
+        let indices = (nodes.iter().enumerate())
+            .map(|(i, x)| (Nr::key(x), i))
+            .collect::<HashMap<_, _>>();
+
+        let edges = (nodes.iter())
+            .map(|x| {
+                (x.neighbors())
+                    .filter(|x| x.is_strong())
+                    .filter_map(|x| indices.get(x.key()).copied())
+                    .collect()
+            })
+            .collect();
+
+        Self { edges }
+    }
+
+    /// Returns flags of nodes kept alive by external owners.
+    fn owned<T: ?Sized>(&self, nodes: &[Nr<T>]) -> Vec<bool> {
+        let mut internals = vec![0; nodes.len()];
+        for &target in self.edges.iter().flatten() {
+            internals[target] += 1;
+        }
+
+        let starts = (0..nodes.len())
+            .filter(|&i| Nr::strong_count(&nodes[i]) > internals[i] + 1)
+            .collect::<Vec<_>>();
+
+        self.reach(&starts)
+    }
+
+    /// Returns graph without links from and to excluded nodes.
+    fn without(&self, excluded: &[bool]) -> Self {
+        let edges = (self.edges.iter().enumerate())
+            .map(|(i, x)| match excluded[i] {
+                true => vec![],
+                false => x.iter().copied().filter(|&x| !excluded[x]).collect(),
+            })
+            .collect();
+
+        Self { edges }
+    }
+
+    /// Returns flags of nodes reachable from starts.
+    fn reach(&self, starts: &[usize]) -> Vec<bool> {
+        let mut ret = vec![false; self.edges.len()];
+        let mut stack = starts.to_vec();
+        while let Some(node) = stack.pop() {
+            if !ret[node] {
+                ret[node] = true;
+                stack.extend(&self.edges[node]);
+            }
+        }
+
+        ret
+    }
+
+    /// Returns strongly connected components (Tarjan's algorithm).
+    fn components(&self) -> Vec<Vec<usize>> {
+        const NONE: usize = usize::MAX;
+        let len = self.edges.len();
+        let mut index = vec![NONE; len];
+        let mut low = vec![NONE; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = Vec::new();
+        let mut next = 0;
+        let mut ret = Vec::new();
+
+        for start in 0..len {
+            if index[start] != NONE {
+                continue;
+            }
+
+            let mut work = vec![(start, 0)];
+            while let Some((node, pos)) = work.last_mut() {
+                let node = *node;
+                if index[node] == NONE {
+                    index[node] = next;
+                    low[node] = next;
+                    next += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                if let Some(&target) = self.edges[node].get(*pos) {
+                    *pos += 1;
+                    if index[target] == NONE {
+                        work.push((target, 0));
+                    } else if on_stack[target] {
+                        low[node] = low[node].min(index[target]);
+                    }
+                    continue;
+                }
+
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+
+                if low[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(x) = stack.pop() {
+                        on_stack[x] = false;
+                        component.push(x);
+                        if x == node {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    ret.push(component);
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Returns cycle path in strongly connected component.
+    fn cycle(&self, component: &[usize]) -> Vec<usize> {
+        let start = component[0];
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for &target in &self.edges[node] {
+                if target == start {
+                    let mut ret = vec![node];
+                    while let Some(&x) = parents.get(ret.last().unwrap()) {
+                        ret.push(x);
+                    }
+                    ret.reverse();
+                    return ret;
+                }
+
+                if component.contains(&target) && !parents.contains_key(&target) {
+                    parents.insert(target, node);
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        unreachable!("Component must contain cycle.")
+    }
+}
@@ -8,6 +8,10 @@
 //!
 //! - [`Nr`] - like [`Rc`]
 //! - [`Nw`] - like [`Weak`].
+//! - [`Na`] - like [`Arc`] (thread-safe version of [`Nr`]).
+//! - [`NwA`] - like [`sync::Weak`] (thread-safe version of [`Nw`]).
+//! - [`AnyNr`] and [`AnyNw`] - type-erased versions of [`Nr`] and [`Nw`].
+//! - [`PinNr`] and [`PinNw`] - pinned versions of [`Nr`] and [`Nw`].
 //!
 //! These smart pointers behavior is similar to [`Rc`] and [`Weak`].<br/>
 //! However, These smart pointer comparison is based on location.
@@ -20,17 +24,108 @@
 //! For example, comparison of [`Nr::eq`] is based on identity of node address.<br/>
 //! On the other hand comparison of [`Rc::eq`] is based on inner value.
 //!
+//! Strong and weak pointers of the same type (e.g. `Nr<T>` and `Nw<T>`)
+//! can be compared by operators. Pointers of different types (e.g. `Nr<T>`
+//! and `Nr<dyn Any>`) are compared by [`Nr::ptr_eq`], [`Nr::ptr_eq_weak`],
+//! and [`Nr::ptr_cmp`] instead, because generic operators between them break
+//! type inference of existing code. All of them ignore pointer metadata such
+//! as vtables.
+//!
+//! # Crate features
+//!
+//! - `serde` - Serde support of [`Nr`] and [`Nw`] (see `SerdeGraph`).
+//! - `nightly` - Unsize coercion of node pointers, `UniqueNr`, `UniqueNa`,
+//!   and iterative cyclic construction of multiple nodes (requires nightly Rust).
+//!
+//! # Unsize handling
+//!
+//! To support unsize conversions with smart pointers, unstable feature
+//! `CoerceUnsized` is required. So, `Nr<Type>` to `Nr<dyn Trait>`
+//! conversion is supported only with `nightly` feature.
+//!
+//! As workaround, we provide macros [`nr_unsize`], [`nw_unsize`],
+//! [`na_unsize`], and [`nwa_unsize`].
+//!
 //! [`Rc`]: std::rc::Rc
+//! [`Arc`]: std::sync::Arc
+//! [`sync::Weak`]: std::sync::Weak
 //! [`Rc::eq`]: std::rc::Rc::eq
 //! [`Weak`]: std::rc::Weak
 //! [`HashSet`]: std::collections::HashSet
 
+#![cfg_attr(
+    feature = "nightly",
+    feature(coerce_unsized, dispatch_from_dyn, unique_rc_arc, unsize)
+)]
 #![warn(missing_docs)]
 
 pub mod prelude;
+pub use any_nr::*;
+pub use any_nw::*;
+pub use bfs::*;
+pub use debug_graph::DebugGraph;
+pub use dfs::*;
+pub use dfs_post_order::*;
+pub use dot_writer::*;
+pub use graph::*;
+pub use leak_report::*;
+pub use link::*;
+pub use lru_cache::*;
+pub use na::*;
+pub use neighbors::*;
+pub use node_attr_map::*;
+pub use node_borrow_error::*;
+pub use node_cell::*;
+pub use node_key::*;
+pub use node_list::*;
 pub use nr::*;
 pub use nw::*;
+pub use nwa::*;
+pub use pin_nr::*;
+pub use pin_nw::*;
+pub use seq_nr::*;
+pub use seq_nw::*;
+#[cfg(feature = "serde")]
+pub use serde_graph::*;
+pub use tree_node::*;
+#[cfg(feature = "nightly")]
+pub use unique_na::*;
+#[cfg(feature = "nightly")]
+pub use unique_nr::*;
+pub use weak_node_set::*;
 
+mod any_nr;
+mod any_nw;
+mod bfs;
+mod debug_graph;
+mod dfs;
+mod dfs_post_order;
+mod dot_writer;
+mod graph;
+mod leak_report;
+mod link;
+mod lru_cache;
+mod na;
+mod neighbors;
+mod node_attr_map;
+mod node_borrow_error;
+mod node_cell;
+mod node_key;
+mod node_list;
 mod nr;
 mod nw;
+mod nwa;
+mod pin_nr;
+mod pin_nw;
+mod seq_nr;
+mod seq_nw;
+#[cfg(feature = "serde")]
+mod serde_graph;
+mod tree_node;
+#[cfg(feature = "nightly")]
+mod unique_na;
+#[cfg(feature = "nightly")]
+mod unique_nr;
+mod unsize;
 mod util;
+mod weak_node_set;
@@ -0,0 +1,67 @@
+//! Provider of [`Link`].
+
+use crate::prelude::*;
+
+/// Link to node (strong or weak).
+#[derive(Debug)]
+pub enum Link<T: ?Sized> {
+    /// Strong link.
+    Strong(Nr<T>),
+    /// Weak link.
+    Weak(Nw<T>),
+}
+
+impl<T: ?Sized> Link<T> {
+    /// Returns `true` if this link is strong.
+    #[must_use]
+    pub fn is_strong(&self) -> bool {
+        matches!(self, Self::Strong(_))
+    }
+
+    /// Returns `true` if this link is weak.
+    #[must_use]
+    pub fn is_weak(&self) -> bool {
+        matches!(self, Self::Weak(_))
+    }
+
+    /// Returns key of the target node identity.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        match self {
+            Self::Strong(x) => Nr::key(x),
+            Self::Weak(x) => x.key(),
+        }
+    }
+
+    /// Returns strong pointer to the target node.
+    ///
+    /// Returns [`None`] if the target node has since been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Nr<T>> {
+        match self {
+            Self::Strong(x) => Some(x.clone()),
+            Self::Weak(x) => x.upgrade(),
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for Link<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Strong(x) => Self::Strong(x.clone()),
+            Self::Weak(x) => Self::Weak(x.clone()),
+        }
+    }
+}
+
+impl<T: ?Sized> From<Nr<T>> for Link<T> {
+    fn from(value: Nr<T>) -> Self {
+        Self::Strong(value)
+    }
+}
+
+impl<T: ?Sized> From<Nw<T>> for Link<T> {
+    fn from(value: Nw<T>) -> Self {
+        Self::Weak(value)
+    }
+}
@@ -0,0 +1,207 @@
+//! Provider of [`LruCache`].
+
+use crate::prelude::*;
+use crate::{ListNode, NodeList, NodeListIter};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::Hash;
+
+type EvictFn<K, V> = Box<dyn FnMut(Nr<ListNode<LruEntry<K, V>>>)>;
+
+/// Cache with least recently used eviction.
+///
+/// Entries are [`NodeList`] nodes ordered by recency (most recent first).
+/// Entry nodes returned by this cache can be held as [`Nr`] or [`Nw`].
+/// Once an entry is evicted or removed, the cache no longer keeps it alive.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::LruCache;
+/// # use easy_node::prelude::*;
+/// let mut cache = LruCache::new(2);
+/// let a = Nr::downgrade(&cache.insert("a", 1));
+/// cache.insert("b", 2);
+/// cache.get("a");
+/// cache.insert("c", 3);
+/// assert!(cache.contains_key("a"));
+/// assert!(!cache.contains_key("b"));
+/// assert!(a.upgrade().is_some());
+/// ```
+pub struct LruCache<K, V> {
+    capacity: usize,
+    entries: NodeList<LruEntry<K, V>>,
+    index: HashMap<K, Nr<ListNode<LruEntry<K, V>>>>,
+    on_evict: Option<EvictFn<K, V>>,
+}
+
+impl<K: Clone + Eq + Hash, V> LruCache<K, V> {
+    /// Creates a new empty instance with the capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Capacity must be non-zero.");
+        Self {
+            capacity,
+            entries: NodeList::new(),
+            index: HashMap::new(),
+            on_evict: None,
+        }
+    }
+
+    /// Sets function called with each evicted entry.
+    ///
+    /// Eviction occurs only when insertion exceeds the capacity.
+    #[must_use]
+    pub fn on_evict<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(Nr<ListNode<LruEntry<K, V>>>) + 'static,
+    {
+        self.on_evict = Some(Box::new(f));
+        self
+    }
+
+    /// Returns the maximum number of entries.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this cache contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if this cache contains entry for the key.
+    ///
+    /// This does not update the recency.
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Returns entry for the key, marking it as most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<Nr<ListNode<LruEntry<K, V>>>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let ret = self.index.get(key)?;
+        self.entries.move_to_front(ret);
+        Some(ret.clone())
+    }
+
+    /// Returns entry for the key without updating the recency.
+    #[must_use]
+    pub fn peek<Q>(&self, key: &Q) -> Option<Nr<ListNode<LruEntry<K, V>>>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index.get(key).cloned()
+    }
+
+    /// Inserts entry as most recently used, returning its node.
+    ///
+    /// Old entry for the same key is removed (not evicted). If the number of
+    /// entries exceeds the capacity, the least recently used one is evicted.
+    pub fn insert(&mut self, key: K, value: V) -> Nr<ListNode<LruEntry<K, V>>> {
+        self.remove(&key);
+        let entry = LruEntry::new(key.clone(), value);
+        let ret = self.entries.push_front(entry);
+        self.index.insert(key, ret.clone());
+        if self.entries.len() > self.capacity {
+            self.evict();
+        }
+
+        ret
+    }
+
+    /// Removes entry for the key, returning its node.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Nr<ListNode<LruEntry<K, V>>>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let ret = self.index.remove(key)?;
+        self.entries.remove(&ret);
+        Some(ret)
+    }
+
+    /// Removes all entries (without eviction).
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over entries from most recently used.
+    pub fn iter(&self) -> NodeListIter<'_, LruEntry<K, V>> {
+        self.entries.iter()
+    }
+
+    /// Evicts the least recently used entry.
+    fn evict(&mut self) {
+        let node = self.entries.pop_back().unwrap();
+        self.index.remove(&node.value().key);
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(node);
+        }
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("LruCache")
+            .field("capacity", &self.capacity)
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+/// Entry of [`LruCache`].
+pub struct LruEntry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K, V> LruEntry<K, V> {
+    fn new(key: K, value: V) -> Self {
+        Self { key, value }
+    }
+
+    /// Returns the key of this entry.
+    #[must_use]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the value of this entry.
+    #[must_use]
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for LruEntry<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("LruEntry")
+            .field(&self.key)
+            .field(&self.value)
+            .finish()
+    }
+}
@@ -0,0 +1,499 @@
+//! Provider of [`Na`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use crate::util::*;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "nightly")]
+use std::iter;
+#[cfg(feature = "nightly")]
+use std::marker::Unsize;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+#[cfg(feature = "nightly")]
+use std::ops::{CoerceUnsized, DispatchFromDyn};
+use std::ptr;
+#[cfg(feature = "nightly")]
+use std::sync::UniqueArc;
+use std::sync::{Arc, Weak};
+
+/// Thread-safe strong reference to node.
+#[repr(transparent)]
+#[derive(Default)]
+pub struct Na<T: ?Sized>(Arc<T>);
+
+impl<T> Na<T> {
+    /// Creates a new instance.
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Creates self-referencing instance.
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&NwA<T>) -> T,
+    {
+        let conv_arg = |w: &_| NwA::from_base(Weak::clone(w));
+        let base = Arc::new_cyclic(|w| data_fn(&conv_arg(w)));
+        Self(base)
+    }
+
+    /// Creates self-referencing instance with fallible function.
+    ///
+    /// If `data_fn` returns [`Err`], no node is created and the error is
+    /// returned.
+    pub fn try_new_cyclic<E, F>(data_fn: F) -> std::result::Result<Self, E>
+    where
+        F: FnOnce(&NwA<T>) -> std::result::Result<T, E>,
+    {
+        let mut err = None;
+        let base = Arc::new_cyclic(|w| {
+            let weak = NwA::from_base(cast_weak(Weak::clone(w)));
+            match data_fn(&weak) {
+                Ok(x) => MaybeUninit::new(x),
+                Err(e) => {
+                    err = Some(e);
+                    MaybeUninit::uninit()
+                }
+            }
+        });
+
+        match err {
+            None => Ok(Self(unsafe { base.assume_init() })),
+            Some(e) => Err(e),
+        }
+    }
+
+    /// Creates multiple instances referencing each other.
+    ///
+    /// This is array version of [`new_cyclic_vec`](Self::new_cyclic_vec).
+    pub fn new_cyclic_array<const N: usize, F>(data_fn: F) -> [Self; N]
+    where
+        F: FnOnce(&[NwA<T>; N]) -> [T; N],
+    {
+        let data_fn = |ws: &[_]| Vec::from(data_fn(ws.try_into().unwrap()));
+        let ret = Self::new_cyclic_vec(N, data_fn);
+        ret.try_into().unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Creates multiple instances referencing each other.
+    ///
+    /// `data_fn` receives weak pointers to all nodes before they are created,
+    /// and returns values of nodes in the same order. Like [`new_cyclic`],
+    /// the weak pointers can not be upgraded until all nodes are created.
+    ///
+    /// With `nightly` feature, nodes are created iteratively. Otherwise,
+    /// nodes are created by nested calls of [`Arc::new_cyclic`]. So, stack
+    /// usage grows with the number of nodes, and too many nodes overflow
+    /// the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of returned values is not `len`.
+    ///
+    /// [`new_cyclic`]: Self::new_cyclic
+    pub fn new_cyclic_vec<F>(len: usize, data_fn: F) -> Vec<Self>
+    where
+        F: FnOnce(&[NwA<T>]) -> Vec<T>,
+    {
+        new_cyclic_seq(len, data_fn)
+    }
+
+    /// Returns the inner value, if this node has exactly one strong reference.
+    ///
+    /// Otherwise, [`Err`] is returned with the same node that was passed in.
+    pub fn try_unwrap(this: Self) -> std::result::Result<T, Self> {
+        Arc::try_unwrap(this.0).map_err(Self)
+    }
+
+    /// Returns the inner value, if this node has exactly one strong reference.
+    ///
+    /// Otherwise, [`None`] is returned and the node is dropped.
+    pub fn into_inner(this: Self) -> Option<T> {
+        Arc::into_inner(this.0)
+    }
+}
+
+impl<T: Clone> Na<T> {
+    /// Returns the inner value, cloning it if there are other strong references.
+    pub fn unwrap_or_clone(this: Self) -> T {
+        Arc::unwrap_or_clone(this.0)
+    }
+
+    /// Returns mutable reference to the inner value.
+    ///
+    /// If there are other pointers to this node, the inner value is cloned to
+    /// a new allocation to ensure unique ownership. In that case, this pointer
+    /// moves to the new node, so its identity (used by comparison and hash)
+    /// changes, and weak pointers to the old node are not related to it.
+    pub fn make_mut(this: &mut Self) -> &mut T {
+        Arc::make_mut(&mut this.0)
+    }
+}
+
+impl<T: ?Sized> Na<T> {
+    /// Creates reference from base object.
+    #[must_use]
+    pub fn as_base(base: &Arc<T>) -> &Self {
+        unsafe { mem::transmute(base) }
+    }
+
+    /// Creates instance from base object.
+    #[must_use]
+    pub fn from_base(base: Arc<T>) -> Self {
+        Self(base)
+    }
+
+    /// Consumes this pointer, returning base object.
+    #[must_use]
+    pub fn into_base(this: Self) -> Arc<T> {
+        this.0
+    }
+
+    /// Returns a raw pointer to the data.
+    #[must_use]
+    pub fn as_ptr(this: &Self) -> *const T {
+        Arc::as_ptr(&this.0)
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(this: &Self) -> &NodeKey {
+        NodeKey::from_ptr(Self::as_ptr(this))
+    }
+
+    /// Returns `true` if two pointers point to the same node.
+    ///
+    /// Unlike [`Arc::ptr_eq`], pointee types may differ, and pointer
+    /// metadata such as vtables is ignored.
+    #[must_use]
+    pub fn ptr_eq<U: ?Sized>(this: &Self, other: &Na<U>) -> bool {
+        ptr::addr_eq(Self::as_ptr(this), Na::as_ptr(other))
+    }
+
+    /// Returns `true` if this pointer and the weak pointer point to the same node.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_eq_weak<U: ?Sized>(this: &Self, other: &NwA<U>) -> bool {
+        ptr::addr_eq(Self::as_ptr(this), other.as_ptr())
+    }
+
+    /// Compares addresses of two nodes.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_cmp<U: ?Sized>(this: &Self, other: &Na<U>) -> Ordering {
+        cmp_ptr(Self::as_ptr(this), Na::as_ptr(other))
+    }
+
+    /// Returns cycle-aware debug formatter of graph from this node.
+    #[must_use]
+    pub fn debug_graph(this: &Self) -> DebugGraph<'_, Self> {
+        DebugGraph::new(this)
+    }
+
+    /// Consumes this pointer, returning the wrapped pointer.
+    ///
+    /// The pointer is the same as [`as_ptr`], so it can be used as identity.
+    /// To avoid memory leak, the pointer must be converted back
+    /// by [`from_raw`].
+    ///
+    /// [`as_ptr`]: Self::as_ptr
+    /// [`from_raw`]: Self::from_raw
+    #[must_use]
+    pub fn into_raw(this: Self) -> *const T {
+        Arc::into_raw(this.0)
+    }
+
+    /// Creates instance from raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Arc::from_raw`]. Typically, `ptr` must have been
+    /// returned by [`into_raw`], and can be converted back only once.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        Self(unsafe { Arc::from_raw(ptr) })
+    }
+
+    /// Increments the strong reference count of the node at the pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Arc::increment_strong_count`]. Typically, `ptr` must have
+    /// been returned by [`into_raw`], and the node must be still alive.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn increment_strong_count(ptr: *const T) {
+        unsafe { Arc::increment_strong_count(ptr) }
+    }
+
+    /// Decrements the strong reference count of the node at the pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Arc::decrement_strong_count`]. Typically, `ptr` must have
+    /// been returned by [`into_raw`], and the node must be still alive.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn decrement_strong_count(ptr: *const T) {
+        unsafe { Arc::decrement_strong_count(ptr) }
+    }
+
+    /// Returns base object.
+    #[must_use]
+    pub fn base(this: &Self) -> &Arc<T> {
+        &this.0
+    }
+
+    /// Returns mutable reference to the inner value, if this pointer is unique.
+    ///
+    /// Returns [`None`] if there are other strong or weak pointers to this node.
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        Arc::get_mut(&mut this.0)
+    }
+
+    /// Creates weak pointer to this node.
+    #[must_use]
+    pub fn downgrade(this: &Self) -> NwA<T> {
+        NwA::from_base(Arc::downgrade(&this.0))
+    }
+
+    /// Returns the number of strong pointer to this node.
+    pub fn strong_count(this: &Self) -> usize {
+        Arc::strong_count(&this.0)
+    }
+
+    /// Returns the number of weak pointer to this node.
+    pub fn weak_count(this: &Self) -> usize {
+        Arc::weak_count(&this.0)
+    }
+}
+
+impl_strong_downcast!(Na, dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Na<U>> for Na<T> {}
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Na<U>> for Na<T> {}
+
+impl<T: ?Sized> Borrow<NodeKey> for Na<T> {
+    fn borrow(&self) -> &NodeKey {
+        Self::key(self)
+    }
+}
+
+impl<T: ?Sized> Clone for Na<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> Deref for Na<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for Na<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("Na", Self::key(self), &**self, f)
+    }
+}
+
+impl<T: ?Sized + Display> Display for Na<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ?Sized> Eq for Na<T> {}
+
+impl<T> From<T> for Na<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for Na<T> {
+    fn from(value: Box<T>) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl<T: ?Sized> From<Arc<T>> for Na<T> {
+    fn from(value: Arc<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> From<Na<T>> for Arc<T> {
+    fn from(value: Na<T>) -> Self {
+        Na::into_base(value)
+    }
+}
+
+impl<T> From<Vec<T>> for Na<[T]> {
+    fn from(value: Vec<T>) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl<T: Clone> From<&[T]> for Na<[T]> {
+    fn from(value: &[T]) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl From<String> for Na<str> {
+    fn from(value: String) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl From<&str> for Na<str> {
+    fn from(value: &str) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl<T> FromIterator<T> for Na<[T]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Arc::from_iter(iter))
+    }
+}
+
+impl<T: ?Sized> Hash for Na<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Self::key(self).hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for Na<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ptr(Self::as_ptr(self), Self::as_ptr(other))
+    }
+}
+
+impl<T: ?Sized> PartialEq for Na<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> PartialEq<NwA<T>> for Na<T> {
+    fn eq(&self, other: &NwA<T>) -> bool {
+        Self::ptr_eq_weak(self, other)
+    }
+}
+
+impl<T: ?Sized> PartialOrd for Na<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> PartialOrd<NwA<T>> for Na<T> {
+    fn partial_cmp(&self, other: &NwA<T>) -> Option<Ordering> {
+        Some(cmp_ptr(Self::as_ptr(self), other.as_ptr()))
+    }
+}
+
+/// Creates multiple nodes referencing each other from unique nodes.
+#[cfg(feature = "nightly")]
+fn new_cyclic_seq<T, F>(len: usize, data_fn: F) -> Vec<Na<T>>
+where
+    F: FnOnce(&[NwA<T>]) -> Vec<T>,
+{
+    let (uniques, weaks): (Vec<_>, Vec<_>) = (0..len).map(|_| new_uninit()).unzip();
+    let values = data_fn(&weaks);
+    assert_eq!(
+        values.len(),
+        len,
+        "Count of values must match count of nodes."
+    );
+
+    let nodes = iter::zip(uniques, values);
+    nodes.map(|(u, v)| init(u, v)).collect()
+}
+
+/// Creates multiple nodes referencing each other by nested construction.
+#[cfg(not(feature = "nightly"))]
+fn new_cyclic_seq<T, F>(len: usize, data_fn: F) -> Vec<Na<T>>
+where
+    F: FnOnce(&[NwA<T>]) -> Vec<T>,
+{
+    let mut data_fn = Some(data_fn);
+    let mut weaks = Vec::with_capacity(len);
+    let mut ret = Vec::with_capacity(len);
+    let rest = new_cyclic_nested(len, &mut data_fn, &mut weaks, &mut ret);
+    debug_assert!(rest.is_empty());
+    ret.reverse();
+    ret
+}
+
+/// Creates nodes from the current depth, returning values of outer nodes.
+#[cfg(not(feature = "nightly"))]
+fn new_cyclic_nested<T, F>(
+    len: usize,
+    data_fn: &mut Option<F>,
+    weaks: &mut Vec<NwA<T>>,
+    nodes: &mut Vec<Na<T>>,
+) -> Vec<T>
+where
+    F: FnOnce(&[NwA<T>]) -> Vec<T>,
+{
+    if weaks.len() == len {
+        let values = data_fn.take().unwrap()(weaks);
+        assert_eq!(
+            values.len(),
+            len,
+            "Count of values must match count of nodes."
+        );
+        return values;
+    }
+
+    let mut rest = None;
+    let base = Arc::new_cyclic(|w| {
+        weaks.push(NwA::from_base(Weak::clone(w)));
+        let mut values = new_cyclic_nested(len, data_fn, weaks, nodes);
+        let value = values.pop().unwrap();
+        rest = Some(values);
+        value
+    });
+
+    nodes.push(Na(base));
+    rest.unwrap()
+}
+
+/// Creates uninitialized unique node and weak pointer to it.
+#[cfg(feature = "nightly")]
+fn new_uninit<T>() -> (UniqueArc<MaybeUninit<T>>, NwA<T>) {
+    let unique = UniqueArc::new(MaybeUninit::uninit());
+    let weak = UniqueArc::downgrade(&unique);
+    (unique, NwA::from_base(cast_weak(weak)))
+}
+
+/// Initializes unique node and freezes it.
+#[cfg(feature = "nightly")]
+fn init<T>(mut unique: UniqueArc<MaybeUninit<T>>, value: T) -> Na<T> {
+    unique.write(value);
+    Na(unsafe { UniqueArc::into_arc(unique).assume_init() })
+}
+
+/// Casts weak pointer to uninitialized value into weak pointer to the value.
+///
+/// The value must not be accessed through the result until initialized.
+fn cast_weak<T>(weak: Weak<MaybeUninit<T>>) -> Weak<T> {
+    unsafe { Weak::from_raw(Weak::into_raw(weak).cast::<T>()) }
+}
@@ -0,0 +1,14 @@
+//! Provider of [`Neighbors`].
+
+use crate::prelude::*;
+
+/// Node with links to neighbor nodes.
+///
+/// This trait is used by graph traversal such as [`Bfs`] and [`Dfs`].
+///
+/// [`Bfs`]: crate::Bfs
+/// [`Dfs`]: crate::Dfs
+pub trait Neighbors {
+    /// Returns links to neighbor nodes.
+    fn neighbors(&self) -> impl Iterator<Item = Link<Self>>;
+}
@@ -0,0 +1,165 @@
+//! Provider of [`NodeAttrMap`].
+
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::collections::hash_map::{self, Entry};
+
+/// Map from nodes to attribute values.
+///
+/// Keys are held as weak references and identified by node location
+/// (same as [`Nw`] comparison). So, this map does not keep nodes alive.
+///
+/// Once the node is dropped, its entry becomes unreachable. Such entries
+/// are reclaimed lazily by mutation methods ([`entry`], [`insert`],
+/// [`remove`]) and [`iter`], or explicitly by [`purge`]. Until then,
+/// values of dropped nodes are kept.
+///
+/// [`entry`]: Self::entry
+/// [`insert`]: Self::insert
+/// [`remove`]: Self::remove
+/// [`iter`]: Self::iter
+/// [`purge`]: Self::purge
+#[derive(Debug)]
+pub struct NodeAttrMap<T: ?Sized, V> {
+    items: HashMap<Nw<T>, V>,
+}
+
+impl<T: ?Sized, V> NodeAttrMap<T, V> {
+    /// Creates a new empty instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of entries with live node.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.keys().filter(|x| x.strong_count() > 0).count()
+    }
+
+    /// Returns `true` if this map contains no entries with live node.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.keys().all(|x| x.strong_count() == 0)
+    }
+
+    /// Returns `true` if this map contains value for the node.
+    #[must_use]
+    pub fn contains_key(&self, node: &Nr<T>) -> bool {
+        self.items.contains_key(Nr::key(node))
+    }
+
+    /// Returns reference to the value for the node.
+    #[must_use]
+    pub fn get(&self, node: &Nr<T>) -> Option<&V> {
+        self.items.get(Nr::key(node))
+    }
+
+    /// Returns mutable reference to the value for the node.
+    #[must_use]
+    pub fn get_mut(&mut self, node: &Nr<T>) -> Option<&mut V> {
+        self.items.get_mut(Nr::key(node))
+    }
+
+    /// Returns the entry for the node for in-place manipulation.
+    pub fn entry(&mut self, node: &Nr<T>) -> NodeAttrEntry<'_, T, V> {
+        self.purge();
+        NodeAttrEntry(self.items.entry(Nr::downgrade(node)))
+    }
+
+    /// Inserts value for the node.
+    ///
+    /// Returns old value if the node already had one.
+    pub fn insert(&mut self, node: &Nr<T>, value: V) -> Option<V> {
+        self.purge();
+        self.items.insert(Nr::downgrade(node), value)
+    }
+
+    /// Removes value for the node.
+    ///
+    /// Returns the removed value if the node had one.
+    pub fn remove(&mut self, node: &Nr<T>) -> Option<V> {
+        self.purge();
+        self.items.remove(Nr::key(node))
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Removes entries whose node has been dropped.
+    ///
+    /// Returns the number of removed entries.
+    pub fn purge(&mut self) -> usize {
+        let old_len = self.items.len();
+        self.items.retain(|k, _| k.strong_count() > 0);
+        old_len - self.items.len()
+    }
+
+    /// Returns an iterator over entries with live node.
+    ///
+    /// Entries of dropped nodes are purged before iteration.
+    pub fn iter(&mut self) -> NodeAttrMapIter<'_, T, V> {
+        self.purge();
+        NodeAttrMapIter(self.items.iter())
+    }
+}
+
+impl<T: ?Sized, V> Default for NodeAttrMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: ?Sized, V> IntoIterator for &'a mut NodeAttrMap<T, V> {
+    type Item = (Nr<T>, &'a V);
+    type IntoIter = NodeAttrMapIter<'a, T, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Entry of [`NodeAttrMap`] for in-place manipulation.
+#[derive(Debug)]
+pub struct NodeAttrEntry<'a, T: ?Sized, V>(Entry<'a, Nw<T>, V>);
+
+impl<'a, T: ?Sized, V> NodeAttrEntry<'a, T, V> {
+    /// Inserts `value` if vacant, and returns mutable reference to the value.
+    pub fn or_insert(self, value: V) -> &'a mut V {
+        self.0.or_insert(value)
+    }
+
+    /// Inserts result of `f` if vacant, and returns mutable reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, f: F) -> &'a mut V {
+        self.0.or_insert_with(f)
+    }
+
+    /// Modifies the value by `f` if occupied.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        Self(self.0.and_modify(f))
+    }
+}
+
+impl<'a, T: ?Sized, V: Default> NodeAttrEntry<'a, T, V> {
+    /// Inserts default value if vacant, and returns mutable reference to the value.
+    pub fn or_default(self) -> &'a mut V {
+        self.0.or_default()
+    }
+}
+
+/// Iterator over entries of [`NodeAttrMap`].
+#[derive(Debug)]
+pub struct NodeAttrMapIter<'a, T: ?Sized, V>(hash_map::Iter<'a, Nw<T>, V>);
+
+impl<'a, T: ?Sized, V> Iterator for NodeAttrMapIter<'a, T, V> {
+    type Item = (Nr<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(|(k, v)| Some((k.upgrade()?, v)))
+    }
+}
@@ -0,0 +1,89 @@
+//! Provider of [`NodeBorrowError`] and [`NodeBorrowMutError`].
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::panic::Location;
+use std::ptr;
+
+/// Error of immutable borrow of [`NodeCell`](crate::NodeCell).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeBorrowError {
+    addr: usize,
+    location: Option<&'static Location<'static>>,
+}
+
+impl NodeBorrowError {
+    pub(crate) fn new(addr: *const (), location: Option<&'static Location<'static>>) -> Self {
+        let addr = addr.addr();
+        Self { addr, location }
+    }
+
+    /// Returns the address of the node.
+    #[must_use]
+    pub fn addr(&self) -> *const () {
+        ptr::without_provenance(self.addr)
+    }
+
+    /// Returns the location of the outstanding mutable borrow.
+    ///
+    /// This is available only in debug builds.
+    #[must_use]
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+}
+
+impl Display for NodeBorrowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "node {:p} is already mutably borrowed", self.addr())?;
+        fmt_location(self.location, f)
+    }
+}
+
+impl Error for NodeBorrowError {}
+
+/// Error of mutable borrow of [`NodeCell`](crate::NodeCell).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeBorrowMutError {
+    addr: usize,
+    location: Option<&'static Location<'static>>,
+}
+
+impl NodeBorrowMutError {
+    pub(crate) fn new(addr: *const (), location: Option<&'static Location<'static>>) -> Self {
+        let addr = addr.addr();
+        Self { addr, location }
+    }
+
+    /// Returns the address of the node.
+    #[must_use]
+    pub fn addr(&self) -> *const () {
+        ptr::without_provenance(self.addr)
+    }
+
+    /// Returns the location of the outstanding borrow.
+    ///
+    /// If there are multiple immutable borrows, this is the location of
+    /// the earliest one still alive. This is available only in debug builds.
+    #[must_use]
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+}
+
+impl Display for NodeBorrowMutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "node {:p} is already borrowed", self.addr())?;
+        fmt_location(self.location, f)
+    }
+}
+
+impl Error for NodeBorrowMutError {}
+
+/// Formats location of the outstanding borrow.
+fn fmt_location(location: Option<&Location<'_>>, f: &mut Formatter<'_>) -> Result {
+    match location {
+        Some(x) => write!(f, " (borrowed at {x})"),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,233 @@
+//! Provider of [`NodeCell`].
+
+use crate::prelude::*;
+use crate::{NodeBorrowError, NodeBorrowMutError};
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt::{Debug, Formatter, Result};
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+
+/// Mutable memory location for node.
+///
+/// This type is like [`RefCell`], and typically used as `Nr<NodeCell<T>>`.
+/// Unlike [`RefCell`], borrow errors carry the node identity. And in debug
+/// builds, they also carry the location of an outstanding borrow (the
+/// earliest one still alive).
+///
+/// Node identity is the address of this cell. So, it is the same as the
+/// identity of node pointers only if this cell is the whole node (e.g.
+/// `Nr<NodeCell<T>>`), not a field of it.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::NodeCell;
+/// # use easy_node::prelude::*;
+/// let node = Nr::new(NodeCell::new(42));
+/// let _guard = node.borrow();
+/// let err = node.try_borrow_mut().unwrap_err();
+/// assert_eq!(err.addr(), Nr::key(&node).addr());
+/// ```
+#[derive(Default)]
+pub struct NodeCell<T: ?Sized> {
+    tracker: Tracker,
+    value: RefCell<T>,
+}
+
+impl<T> NodeCell<T> {
+    /// Creates a new instance.
+    pub fn new(value: T) -> Self {
+        Self {
+            tracker: Tracker::default(),
+            value: RefCell::new(value),
+        }
+    }
+
+    /// Consumes this cell, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> NodeCell<T> {
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        NodeKey::from_ptr(self)
+    }
+
+    /// Immutably borrows the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    #[track_caller]
+    pub fn borrow(&self) -> NodeRef<'_, T> {
+        match self.try_borrow() {
+            Ok(x) => x,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Immutably borrows the wrapped value, returning an error if
+    /// the value is currently mutably borrowed.
+    #[track_caller]
+    pub fn try_borrow(&self) -> std::result::Result<NodeRef<'_, T>, NodeBorrowError> {
+        let addr = self.key().addr();
+        let value = (self.value.try_borrow())
+            .map_err(|_| NodeBorrowError::new(addr, self.tracker.location()))?;
+
+        let location = Location::caller();
+        self.tracker.enter(location);
+        Ok(NodeRef {
+            value,
+            tracker: &self.tracker,
+            location,
+        })
+    }
+
+    /// Mutably borrows the wrapped value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    #[track_caller]
+    pub fn borrow_mut(&self) -> NodeRefMut<'_, T> {
+        match self.try_borrow_mut() {
+            Ok(x) => x,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Mutably borrows the wrapped value, returning an error if
+    /// the value is currently borrowed.
+    #[track_caller]
+    pub fn try_borrow_mut(&self) -> std::result::Result<NodeRefMut<'_, T>, NodeBorrowMutError> {
+        let addr = self.key().addr();
+        let value = (self.value.try_borrow_mut())
+            .map_err(|_| NodeBorrowMutError::new(addr, self.tracker.location()))?;
+
+        let location = Location::caller();
+        self.tracker.enter(location);
+        Ok(NodeRefMut {
+            value,
+            tracker: &self.tracker,
+            location,
+        })
+    }
+
+    /// Returns mutable reference to the wrapped value.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for NodeCell<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.value.try_borrow() {
+            Ok(x) => f.debug_tuple("NodeCell").field(&&*x).finish(),
+            Err(_) => f.write_str("NodeCell(<borrowed>)"),
+        }
+    }
+}
+
+impl<T> From<T> for NodeCell<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Wrapped immutable borrow of [`NodeCell`].
+pub struct NodeRef<'a, T: ?Sized> {
+    value: Ref<'a, T>,
+    tracker: &'a Tracker,
+    location: &'static Location<'static>,
+}
+
+impl<T: ?Sized + Debug> Debug for NodeRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized> Deref for NodeRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: ?Sized> Drop for NodeRef<'_, T> {
+    fn drop(&mut self) {
+        self.tracker.leave(self.location);
+    }
+}
+
+/// Wrapped mutable borrow of [`NodeCell`].
+pub struct NodeRefMut<'a, T: ?Sized> {
+    value: RefMut<'a, T>,
+    tracker: &'a Tracker,
+    location: &'static Location<'static>,
+}
+
+impl<T: ?Sized + Debug> Debug for NodeRefMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized> Deref for NodeRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: ?Sized> DerefMut for NodeRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: ?Sized> Drop for NodeRefMut<'_, T> {
+    fn drop(&mut self) {
+        self.tracker.leave(self.location);
+    }
+}
+
+/// Tracker of outstanding borrow locations (only in debug builds).
+#[derive(Default)]
+struct Tracker {
+    #[cfg(debug_assertions)]
+    locations: RefCell<Vec<&'static Location<'static>>>,
+}
+
+#[cfg(debug_assertions)]
+impl Tracker {
+    fn location(&self) -> Option<&'static Location<'static>> {
+        self.locations.borrow().first().copied()
+    }
+
+    fn enter(&self, location: &'static Location<'static>) {
+        self.locations.borrow_mut().push(location);
+    }
+
+    fn leave(&self, location: &'static Location<'static>) {
+        let mut locations = self.locations.borrow_mut();
+        let index = locations.iter().position(|x| *x == location).unwrap();
+        locations.remove(index);
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl Tracker {
+    fn location(&self) -> Option<&'static Location<'static>> {
+        None
+    }
+
+    fn enter(&self, _location: &'static Location<'static>) {}
+
+    fn leave(&self, _location: &'static Location<'static>) {}
+}
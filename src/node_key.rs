@@ -0,0 +1,81 @@
+//! Provider of [`NodeKey`].
+
+use crate::util::*;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+/// Opaque key of node identity.
+///
+/// All node pointers can be borrowed as this type. So, sets and maps
+/// keyed by one pointer kind can be queried by other pointer kinds.
+///
+/// This type is only used through reference, and its address is
+/// the address of the node.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::prelude::*;
+/// # use std::collections::HashSet;
+/// let nr = Nr::new(42);
+/// let nw = Nr::downgrade(&nr);
+/// let set = HashSet::from([nr.clone()]);
+/// assert!(set.contains(nw.key()));
+/// ```
+#[repr(C)]
+pub struct NodeKey {
+    _private: [u8; 0],
+}
+
+impl NodeKey {
+    /// Returns key of the node at the address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ptr` is null.
+    #[must_use]
+    pub fn from_ptr<'a, T: ?Sized>(ptr: *const T) -> &'a Self {
+        let ptr = ptr.cast::<Self>();
+        assert!(!ptr.is_null(), "Node address must not be null.");
+        unsafe { &*ptr }
+    }
+
+    /// Returns the address of the node.
+    #[must_use]
+    pub fn addr(&self) -> *const () {
+        (self as *const Self).cast()
+    }
+}
+
+impl Debug for NodeKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("NodeKey").field(&self.addr()).finish()
+    }
+}
+
+impl Eq for NodeKey {}
+
+impl Hash for NodeKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state);
+    }
+}
+
+impl Ord for NodeKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ptr(self.addr(), other.addr())
+    }
+}
+
+impl PartialEq for NodeKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl PartialOrd for NodeKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
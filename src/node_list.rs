@@ -0,0 +1,357 @@
+//! Provider of [`NodeList`].
+
+use crate::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::sync::atomic::{self, AtomicU64};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Doubly linked list with node handles.
+///
+/// Elements are [`ListNode`] nodes, and each node is linked to the next
+/// node strongly and to the previous node weakly. Node pointers returned by
+/// this list can be used as handles of elements (they are compared by node
+/// identity), and elements can be removed or moved with them in O(1).
+///
+/// Removed nodes are detached from the list. And, on dropping this list,
+/// all nodes are detached. So, handles never keep other nodes alive.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::NodeList;
+/// let mut list = NodeList::new();
+/// let a = list.push_back(1);
+/// let b = list.push_back(2);
+/// list.move_to_front(&b);
+/// list.remove(&a);
+/// assert_eq!(list.iter().map(|x| *x.value()).collect::<Vec<_>>(), [2]);
+/// ```
+pub struct NodeList<T> {
+    id: u64,
+    head: Option<Nr<ListNode<T>>>,
+    tail: Nw<ListNode<T>>,
+    len: usize,
+}
+
+impl<T> NodeList<T> {
+    /// Creates a new empty instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            head: None,
+            tail: Nw::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this list contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the first node.
+    #[must_use]
+    pub fn front(&self) -> Option<Nr<ListNode<T>>> {
+        self.head.clone()
+    }
+
+    /// Returns the last node.
+    #[must_use]
+    pub fn back(&self) -> Option<Nr<ListNode<T>>> {
+        self.tail.upgrade()
+    }
+
+    /// Returns `true` if the node is an element of this list.
+    #[must_use]
+    pub fn contains(&self, node: &Nr<ListNode<T>>) -> bool {
+        node.list.get() == self.id
+    }
+
+    /// Adds the value to the front, returning its node.
+    pub fn push_front(&mut self, value: T) -> Nr<ListNode<T>> {
+        let node = Nr::new(ListNode::new(value));
+        self.link_after(None, &node);
+        node
+    }
+
+    /// Adds the value to the back, returning its node.
+    pub fn push_back(&mut self, value: T) -> Nr<ListNode<T>> {
+        let node = Nr::new(ListNode::new(value));
+        self.link_after(self.back().as_ref(), &node);
+        node
+    }
+
+    /// Removes the first node, returning it.
+    pub fn pop_front(&mut self) -> Option<Nr<ListNode<T>>> {
+        let node = self.front()?;
+        self.unlink(&node);
+        Some(node)
+    }
+
+    /// Removes the last node, returning it.
+    pub fn pop_back(&mut self) -> Option<Nr<ListNode<T>>> {
+        let node = self.back()?;
+        self.unlink(&node);
+        Some(node)
+    }
+
+    /// Inserts the value after the node, returning its node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not an element of this list.
+    pub fn insert_after(&mut self, node: &Nr<ListNode<T>>, value: T) -> Nr<ListNode<T>> {
+        assert!(self.contains(node), "Node must be element of this list.");
+        let ret = Nr::new(ListNode::new(value));
+        self.link_after(Some(node), &ret);
+        ret
+    }
+
+    /// Removes the node from this list.
+    ///
+    /// Returns `true` if the node was an element of this list.
+    pub fn remove(&mut self, node: &Nr<ListNode<T>>) -> bool {
+        if !self.contains(node) {
+            return false;
+        }
+
+        self.unlink(node);
+        true
+    }
+
+    /// Moves the node to the front.
+    ///
+    /// Returns `true` if the node is an element of this list.
+    pub fn move_to_front(&mut self, node: &Nr<ListNode<T>>) -> bool {
+        if !self.contains(node) {
+            return false;
+        }
+
+        self.unlink(node);
+        self.link_after(None, node);
+        true
+    }
+
+    /// Removes all nodes.
+    pub fn clear(&mut self) {
+        let mut next = self.head.take();
+        while let Some(node) = next {
+            next = node.next.take();
+            node.prev.replace(Nw::new());
+            node.list.set(0);
+        }
+
+        self.tail = Nw::new();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over nodes.
+    pub fn iter(&self) -> NodeListIter<'_, T> {
+        NodeListIter {
+            front: self.front(),
+            back: self.back(),
+            len: self.len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Links the node after the previous node (or at the front).
+    fn link_after(&mut self, prev: Option<&Nr<ListNode<T>>>, node: &Nr<ListNode<T>>) {
+        let next = match prev {
+            Some(x) => x.next.take(),
+            None => self.head.take(),
+        };
+
+        match &next {
+            Some(x) => *x.prev.borrow_mut() = Nr::downgrade(node),
+            None => self.tail = Nr::downgrade(node),
+        }
+
+        *node.prev.borrow_mut() = prev.map(Nr::downgrade).unwrap_or_else(Nw::new);
+        *node.next.borrow_mut() = next;
+        match prev {
+            Some(x) => *x.next.borrow_mut() = Some(node.clone()),
+            None => self.head = Some(node.clone()),
+        }
+
+        node.list.set(self.id);
+        self.len += 1;
+    }
+
+    /// Unlinks the node from this list.
+    fn unlink(&mut self, node: &Nr<ListNode<T>>) {
+        let prev = node.prev.replace(Nw::new()).upgrade();
+        let next = node.next.take();
+        let prev_link = prev.as_ref().map(Nr::downgrade).unwrap_or_else(Nw::new);
+        match &next {
+            Some(x) => *x.prev.borrow_mut() = prev_link,
+            None => self.tail = prev_link,
+        }
+
+        match &prev {
+            Some(x) => *x.next.borrow_mut() = next,
+            None => self.head = next,
+        }
+
+        node.list.set(0);
+        self.len -= 1;
+    }
+}
+
+impl<T: Debug> Debug for NodeList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for NodeList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for NodeList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Extend<T> for NodeList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for NodeList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NodeList<T> {
+    type Item = Nr<ListNode<T>>;
+    type IntoIter = NodeListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Element node of [`NodeList`].
+pub struct ListNode<T> {
+    list: Cell<u64>,
+    next: RefCell<Option<Nr<Self>>>,
+    prev: RefCell<Nw<Self>>,
+    value: T,
+}
+
+impl<T> ListNode<T> {
+    fn new(value: T) -> Self {
+        Self {
+            list: Cell::new(0),
+            next: RefCell::new(None),
+            prev: RefCell::new(Nw::new()),
+            value,
+        }
+    }
+
+    /// Returns the value of this node.
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns `true` if this node is an element of some list.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.list.get() != 0
+    }
+
+    /// Returns the next node.
+    #[must_use]
+    pub fn next(&self) -> Option<Nr<Self>> {
+        self.next.borrow().clone()
+    }
+
+    /// Returns the previous node.
+    #[must_use]
+    pub fn prev(&self) -> Option<Nr<Self>> {
+        self.prev.borrow().upgrade()
+    }
+}
+
+impl<T: Debug> Debug for ListNode<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("ListNode").field(&self.value).finish()
+    }
+}
+
+/// Iterator over nodes of [`NodeList`].
+pub struct NodeListIter<'a, T> {
+    front: Option<Nr<ListNode<T>>>,
+    back: Option<Nr<ListNode<T>>>,
+    len: usize,
+    phantom: PhantomData<&'a NodeList<T>>,
+}
+
+impl<T: Debug> Debug for NodeListIter<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("NodeListIter")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T> Iterator for NodeListIter<'_, T> {
+    type Item = Nr<ListNode<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let ret = self.front.take()?;
+        self.front = ret.next();
+        self.len -= 1;
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for NodeListIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let ret = self.back.take()?;
+        self.back = ret.prev();
+        self.len -= 1;
+        Some(ret)
+    }
+}
+
+impl<T> ExactSizeIterator for NodeListIter<'_, T> {}
+
+impl<T> FusedIterator for NodeListIter<'_, T> {}
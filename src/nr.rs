@@ -1,17 +1,31 @@
 //! Provider of [`Nr`].
 
+use crate::PinNr;
+use crate::debug_graph::*;
 use crate::prelude::*;
 use crate::util::*;
+use std::any::Any;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
+#[cfg(feature = "nightly")]
+use std::iter;
+#[cfg(feature = "nightly")]
+use std::marker::Unsize;
 use std::mem;
+use std::mem::MaybeUninit;
 use std::ops::Deref;
+#[cfg(feature = "nightly")]
+use std::ops::{CoerceUnsized, DispatchFromDyn};
+use std::ptr;
+#[cfg(feature = "nightly")]
+use std::rc::UniqueRc;
 use std::rc::{Rc, Weak};
 
 /// Strong reference to node.
 #[repr(transparent)]
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Nr<T: ?Sized>(Rc<T>);
 
 impl<T> Nr<T> {
@@ -29,6 +43,113 @@ impl<T> Nr<T> {
         let base = Rc::new_cyclic(|w| data_fn(&conv_arg(w)));
         Self(base)
     }
+
+    /// Creates self-referencing instance with fallible function.
+    ///
+    /// If `data_fn` returns [`Err`], no node is created and the error is
+    /// returned.
+    pub fn try_new_cyclic<E, F>(data_fn: F) -> std::result::Result<Self, E>
+    where
+        F: FnOnce(&Nw<T>) -> std::result::Result<T, E>,
+    {
+        let mut err = None;
+        let base = Rc::new_cyclic(|w| {
+            let weak = Nw::from_base(cast_weak(Weak::clone(w)));
+            match data_fn(&weak) {
+                Ok(x) => MaybeUninit::new(x),
+                Err(e) => {
+                    err = Some(e);
+                    MaybeUninit::uninit()
+                }
+            }
+        });
+
+        match err {
+            None => Ok(Self(unsafe { base.assume_init() })),
+            Some(e) => Err(e),
+        }
+    }
+
+    /// Creates multiple instances referencing each other.
+    ///
+    /// This is array version of [`new_cyclic_vec`](Self::new_cyclic_vec).
+    pub fn new_cyclic_array<const N: usize, F>(data_fn: F) -> [Self; N]
+    where
+        F: FnOnce(&[Nw<T>; N]) -> [T; N],
+    {
+        let data_fn = |ws: &[_]| Vec::from(data_fn(ws.try_into().unwrap()));
+        let ret = Self::new_cyclic_vec(N, data_fn);
+        ret.try_into().unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Creates multiple instances referencing each other.
+    ///
+    /// `data_fn` receives weak pointers to all nodes before they are created,
+    /// and returns values of nodes in the same order. Like [`new_cyclic`],
+    /// the weak pointers can not be upgraded until all nodes are created.
+    ///
+    /// With `nightly` feature, nodes are created iteratively. Otherwise,
+    /// nodes are created by nested calls of [`Rc::new_cyclic`]. So, stack
+    /// usage grows with the number of nodes, and too many nodes overflow
+    /// the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of returned values is not `len`.
+    ///
+    /// [`new_cyclic`]: Self::new_cyclic
+    pub fn new_cyclic_vec<F>(len: usize, data_fn: F) -> Vec<Self>
+    where
+        F: FnOnce(&[Nw<T>]) -> Vec<T>,
+    {
+        new_cyclic_seq(len, data_fn)
+    }
+
+    /// Creates a new pinned instance.
+    pub fn pin(value: T) -> PinNr<T> {
+        unsafe { PinNr::new_unchecked(Self::new(value)) }
+    }
+
+    /// Returns the inner value, if this node has exactly one strong reference.
+    ///
+    /// Otherwise, [`Err`] is returned with the same node that was passed in.
+    pub fn try_unwrap(this: Self) -> std::result::Result<T, Self> {
+        Rc::try_unwrap(this.0).map_err(Self)
+    }
+
+    /// Returns the inner value, if this node has exactly one strong reference.
+    ///
+    /// Otherwise, [`None`] is returned and the node is dropped.
+    pub fn into_inner(this: Self) -> Option<T> {
+        Rc::into_inner(this.0)
+    }
+}
+
+impl<T: Clone> Nr<T> {
+    /// Returns the inner value, cloning it if there are other strong references.
+    pub fn unwrap_or_clone(this: Self) -> T {
+        Rc::unwrap_or_clone(this.0)
+    }
+
+    /// Returns mutable reference to the inner value.
+    ///
+    /// If there are other pointers to this node, the inner value is cloned to
+    /// a new allocation to ensure unique ownership. In that case, this pointer
+    /// moves to the new node, so its identity (used by comparison and hash)
+    /// changes, and weak pointers to the old node are not related to it.
+    pub fn make_mut(this: &mut Self) -> &mut T {
+        Rc::make_mut(&mut this.0)
+    }
+}
+
+impl<T: ?Sized + Unpin> Nr<T> {
+    /// Converts this node into pinned node.
+    ///
+    /// Since the node type is [`Unpin`], this is always safe.
+    #[must_use]
+    pub fn into_pin(this: Self) -> PinNr<T> {
+        PinNr::new(this)
+    }
 }
 
 impl<T: ?Sized> Nr<T> {
@@ -44,18 +165,117 @@ impl<T: ?Sized> Nr<T> {
         Self(base)
     }
 
+    /// Consumes this pointer, returning base object.
+    #[must_use]
+    pub fn into_base(this: Self) -> Rc<T> {
+        this.0
+    }
+
     /// Returns a raw pointer to the data.
     #[must_use]
     pub fn as_ptr(this: &Self) -> *const T {
         Rc::as_ptr(&this.0)
     }
 
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(this: &Self) -> &NodeKey {
+        NodeKey::from_ptr(Self::as_ptr(this))
+    }
+
+    /// Returns `true` if two pointers point to the same node.
+    ///
+    /// Unlike [`Rc::ptr_eq`], pointee types may differ, and pointer
+    /// metadata such as vtables is ignored.
+    #[must_use]
+    pub fn ptr_eq<U: ?Sized>(this: &Self, other: &Nr<U>) -> bool {
+        ptr::addr_eq(Self::as_ptr(this), Nr::as_ptr(other))
+    }
+
+    /// Returns `true` if this pointer and the weak pointer point to the same node.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_eq_weak<U: ?Sized>(this: &Self, other: &Nw<U>) -> bool {
+        ptr::addr_eq(Self::as_ptr(this), other.as_ptr())
+    }
+
+    /// Compares addresses of two nodes.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_cmp<U: ?Sized>(this: &Self, other: &Nr<U>) -> Ordering {
+        cmp_ptr(Self::as_ptr(this), Nr::as_ptr(other))
+    }
+
+    /// Returns cycle-aware debug formatter of graph from this node.
+    #[must_use]
+    pub fn debug_graph(this: &Self) -> DebugGraph<'_, Self> {
+        DebugGraph::new(this)
+    }
+
+    /// Consumes this pointer, returning the wrapped pointer.
+    ///
+    /// The pointer is the same as [`as_ptr`], so it can be used as identity.
+    /// To avoid memory leak, the pointer must be converted back
+    /// by [`from_raw`].
+    ///
+    /// [`as_ptr`]: Self::as_ptr
+    /// [`from_raw`]: Self::from_raw
+    #[must_use]
+    pub fn into_raw(this: Self) -> *const T {
+        Rc::into_raw(this.0)
+    }
+
+    /// Creates instance from raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Rc::from_raw`]. Typically, `ptr` must have been
+    /// returned by [`into_raw`], and can be converted back only once.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        Self(unsafe { Rc::from_raw(ptr) })
+    }
+
+    /// Increments the strong reference count of the node at the pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Rc::increment_strong_count`]. Typically, `ptr` must have
+    /// been returned by [`into_raw`], and the node must be still alive.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn increment_strong_count(ptr: *const T) {
+        unsafe { Rc::increment_strong_count(ptr) }
+    }
+
+    /// Decrements the strong reference count of the node at the pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Rc::decrement_strong_count`]. Typically, `ptr` must have
+    /// been returned by [`into_raw`], and the node must be still alive.
+    ///
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn decrement_strong_count(ptr: *const T) {
+        unsafe { Rc::decrement_strong_count(ptr) }
+    }
+
     /// Returns base object.
     #[must_use]
     pub fn base(this: &Self) -> &Rc<T> {
         &this.0
     }
 
+    /// Returns mutable reference to the inner value, if this pointer is unique.
+    ///
+    /// Returns [`None`] if there are other strong or weak pointers to this node.
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        Rc::get_mut(&mut this.0)
+    }
+
     /// Creates weak pointer to this node.
     #[must_use]
     pub fn downgrade(this: &Self) -> Nw<T> {
@@ -73,6 +293,20 @@ impl<T: ?Sized> Nr<T> {
     }
 }
 
+impl_strong_downcast!(Nr, dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Nr<U>> for Nr<T> {}
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Nr<U>> for Nr<T> {}
+
+impl<T: ?Sized> Borrow<NodeKey> for Nr<T> {
+    fn borrow(&self) -> &NodeKey {
+        Self::key(self)
+    }
+}
+
 impl<T: ?Sized> Clone for Nr<T> {
     fn clone(&self) -> Self {
         Self(Rc::clone(&self.0))
@@ -87,6 +321,12 @@ impl<T: ?Sized> Deref for Nr<T> {
     }
 }
 
+impl<T: ?Sized + Debug> Debug for Nr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("Nr", Self::key(self), &**self, f)
+    }
+}
+
 impl<T: ?Sized + Display> Display for Nr<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         self.0.fmt(f)
@@ -95,9 +335,63 @@ impl<T: ?Sized + Display> Display for Nr<T> {
 
 impl<T: ?Sized> Eq for Nr<T> {}
 
+impl<T> From<T> for Nr<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for Nr<T> {
+    fn from(value: Box<T>) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
+impl<T: ?Sized> From<Rc<T>> for Nr<T> {
+    fn from(value: Rc<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> From<Nr<T>> for Rc<T> {
+    fn from(value: Nr<T>) -> Self {
+        Nr::into_base(value)
+    }
+}
+
+impl<T> From<Vec<T>> for Nr<[T]> {
+    fn from(value: Vec<T>) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
+impl<T: Clone> From<&[T]> for Nr<[T]> {
+    fn from(value: &[T]) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
+impl From<String> for Nr<str> {
+    fn from(value: String) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
+impl From<&str> for Nr<str> {
+    fn from(value: &str) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
+impl<T> FromIterator<T> for Nr<[T]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Rc::from_iter(iter))
+    }
+}
+
 impl<T: ?Sized> Hash for Nr<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        Self::as_ptr(self).hash(state);
+        Self::key(self).hash(state);
     }
 }
 
@@ -113,8 +407,109 @@ impl<T: ?Sized> PartialEq for Nr<T> {
     }
 }
 
+impl<T: ?Sized> PartialEq<Nw<T>> for Nr<T> {
+    fn eq(&self, other: &Nw<T>) -> bool {
+        Self::ptr_eq_weak(self, other)
+    }
+}
+
 impl<T: ?Sized> PartialOrd for Nr<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
+
+impl<T: ?Sized> PartialOrd<Nw<T>> for Nr<T> {
+    fn partial_cmp(&self, other: &Nw<T>) -> Option<Ordering> {
+        Some(cmp_ptr(Self::as_ptr(self), other.as_ptr()))
+    }
+}
+
+/// Creates multiple nodes referencing each other from unique nodes.
+#[cfg(feature = "nightly")]
+fn new_cyclic_seq<T, F>(len: usize, data_fn: F) -> Vec<Nr<T>>
+where
+    F: FnOnce(&[Nw<T>]) -> Vec<T>,
+{
+    let (uniques, weaks): (Vec<_>, Vec<_>) = (0..len).map(|_| new_uninit()).unzip();
+    let values = data_fn(&weaks);
+    assert_eq!(
+        values.len(),
+        len,
+        "Count of values must match count of nodes."
+    );
+
+    let nodes = iter::zip(uniques, values);
+    nodes.map(|(u, v)| init(u, v)).collect()
+}
+
+/// Creates multiple nodes referencing each other by nested construction.
+#[cfg(not(feature = "nightly"))]
+fn new_cyclic_seq<T, F>(len: usize, data_fn: F) -> Vec<Nr<T>>
+where
+    F: FnOnce(&[Nw<T>]) -> Vec<T>,
+{
+    let mut data_fn = Some(data_fn);
+    let mut weaks = Vec::with_capacity(len);
+    let mut ret = Vec::with_capacity(len);
+    let rest = new_cyclic_nested(len, &mut data_fn, &mut weaks, &mut ret);
+    debug_assert!(rest.is_empty());
+    ret.reverse();
+    ret
+}
+
+/// Creates nodes from the current depth, returning values of outer nodes.
+#[cfg(not(feature = "nightly"))]
+fn new_cyclic_nested<T, F>(
+    len: usize,
+    data_fn: &mut Option<F>,
+    weaks: &mut Vec<Nw<T>>,
+    nodes: &mut Vec<Nr<T>>,
+) -> Vec<T>
+where
+    F: FnOnce(&[Nw<T>]) -> Vec<T>,
+{
+    if weaks.len() == len {
+        let values = data_fn.take().unwrap()(weaks);
+        assert_eq!(
+            values.len(),
+            len,
+            "Count of values must match count of nodes."
+        );
+        return values;
+    }
+
+    let mut rest = None;
+    let base = Rc::new_cyclic(|w| {
+        weaks.push(Nw::from_base(Weak::clone(w)));
+        let mut values = new_cyclic_nested(len, data_fn, weaks, nodes);
+        let value = values.pop().unwrap();
+        rest = Some(values);
+        value
+    });
+
+    nodes.push(Nr(base));
+    rest.unwrap()
+}
+
+/// Creates uninitialized unique node and weak pointer to it.
+#[cfg(feature = "nightly")]
+fn new_uninit<T>() -> (UniqueRc<MaybeUninit<T>>, Nw<T>) {
+    let unique = UniqueRc::new(MaybeUninit::uninit());
+    let weak = UniqueRc::downgrade(&unique);
+    (unique, Nw::from_base(cast_weak(weak)))
+}
+
+/// Initializes unique node and freezes it.
+#[cfg(feature = "nightly")]
+fn init<T>(mut unique: UniqueRc<MaybeUninit<T>>, value: T) -> Nr<T> {
+    unique.write(value);
+    Nr(unsafe { UniqueRc::into_rc(unique).assume_init() })
+}
+
+/// Casts weak pointer to uninitialized value into weak pointer to the value.
+///
+/// The value must not be accessed through the result until initialized.
+fn cast_weak<T>(weak: Weak<MaybeUninit<T>>) -> Weak<T> {
+    unsafe { Weak::from_raw(Weak::into_raw(weak).cast::<T>()) }
+}
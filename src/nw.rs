@@ -1,15 +1,24 @@
 //! Provider of [`Nw`].
 
+use crate::debug_graph::*;
 use crate::prelude::*;
 use crate::util::*;
+use std::any::Any;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
 use std::hash::{Hash, Hasher};
+#[cfg(feature = "nightly")]
+use std::marker::Unsize;
 use std::mem;
+#[cfg(feature = "nightly")]
+use std::ops::{CoerceUnsized, DispatchFromDyn};
+use std::ptr;
 use std::rc::Weak;
 
 /// Weak reference to node.
 #[repr(transparent)]
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Nw<T: ?Sized>(Weak<T>);
 
 impl<T> Nw<T> {
@@ -37,6 +46,12 @@ impl<T: ?Sized> Nw<T> {
         Self(base)
     }
 
+    /// Consumes this pointer, returning base object.
+    #[must_use]
+    pub fn into_base(self) -> Weak<T> {
+        self.0
+    }
+
     /// Returns a raw pointer to the data.
     ///
     /// The pointer is valid only if there are some strong references.
@@ -48,6 +63,65 @@ impl<T: ?Sized> Nw<T> {
         Weak::as_ptr(&self.0)
     }
 
+    /// Returns key of this node identity.
+    ///
+    /// The key is valid as long as this pointer is alive,
+    /// even if there are no strong references.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        NodeKey::from_ptr(self.as_ptr())
+    }
+
+    /// Returns `true` if two pointers point to the same node.
+    ///
+    /// Unlike [`Weak::ptr_eq`], pointee types may differ, and pointer
+    /// metadata such as vtables is ignored. As with it, dangling pointers
+    /// created by [`new`](Self::new) are equal to each other.
+    #[must_use]
+    pub fn ptr_eq<U: ?Sized>(&self, other: &Nw<U>) -> bool {
+        ptr::addr_eq(self.as_ptr(), other.as_ptr())
+    }
+
+    /// Compares addresses of two nodes.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_cmp<U: ?Sized>(&self, other: &Nw<U>) -> Ordering {
+        cmp_ptr(self.as_ptr(), other.as_ptr())
+    }
+
+    /// Returns cycle-aware debug formatter of graph from this node.
+    #[must_use]
+    pub fn debug_graph(&self) -> DebugGraph<'_, Self> {
+        DebugGraph::new(self)
+    }
+
+    /// Consumes this pointer, returning the wrapped pointer.
+    ///
+    /// The pointer is the same as [`as_ptr`], so it can be used as identity.
+    /// To avoid memory leak, the pointer must be converted back
+    /// by [`from_raw`].
+    ///
+    /// [`as_ptr`]: Self::as_ptr
+    /// [`from_raw`]: Self::from_raw
+    #[must_use]
+    pub fn into_raw(self) -> *const T {
+        self.0.into_raw()
+    }
+
+    /// Creates instance from raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Weak::from_raw`]. Typically, `ptr` must have been
+    /// returned by [`into_raw`], and can be converted back only once.
+    ///
+    /// [`Weak::from_raw`]: std::rc::Weak::from_raw
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        Self(unsafe { Weak::from_raw(ptr) })
+    }
+
     /// Returns base object.
     #[must_use]
     pub fn base(&self) -> &Weak<T> {
@@ -75,17 +149,58 @@ impl<T: ?Sized> Nw<T> {
     }
 }
 
+impl_weak_downcast!(Nw, dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Nw<U>> for Nw<T> {}
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<Nw<U>> for Nw<T> {}
+
+impl<T: ?Sized> Borrow<NodeKey> for Nw<T> {
+    fn borrow(&self) -> &NodeKey {
+        self.key()
+    }
+}
+
 impl<T: ?Sized> Clone for Nw<T> {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
 
+impl<T: ?Sized + Debug> Debug for Nw<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.upgrade().filter(|_| Label::is_active()) {
+            Some(x) => x.fmt(f),
+            None => f.debug_tuple("Nw").field(&self.0).finish(),
+        }
+    }
+}
+
 impl<T: ?Sized> Eq for Nw<T> {}
 
+impl<T: ?Sized> From<&Nr<T>> for Nw<T> {
+    fn from(value: &Nr<T>) -> Self {
+        Nr::downgrade(value)
+    }
+}
+
+impl<T: ?Sized> From<Weak<T>> for Nw<T> {
+    fn from(value: Weak<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> From<Nw<T>> for Weak<T> {
+    fn from(value: Nw<T>) -> Self {
+        value.into_base()
+    }
+}
+
 impl<T: ?Sized> Hash for Nw<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_ptr().hash(state);
+        self.key().hash(state);
     }
 }
 
@@ -101,8 +216,20 @@ impl<T: ?Sized> PartialEq for Nw<T> {
     }
 }
 
+impl<T: ?Sized> PartialEq<Nr<T>> for Nw<T> {
+    fn eq(&self, other: &Nr<T>) -> bool {
+        Nr::ptr_eq_weak(other, self)
+    }
+}
+
 impl<T: ?Sized> PartialOrd for Nw<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
+
+impl<T: ?Sized> PartialOrd<Nr<T>> for Nw<T> {
+    fn partial_cmp(&self, other: &Nr<T>) -> Option<Ordering> {
+        Some(cmp_ptr(self.as_ptr(), Nr::as_ptr(other)))
+    }
+}
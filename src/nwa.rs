@@ -0,0 +1,235 @@
+//! Provider of [`NwA`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use crate::util::*;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "nightly")]
+use std::marker::Unsize;
+use std::mem;
+#[cfg(feature = "nightly")]
+use std::ops::{CoerceUnsized, DispatchFromDyn};
+use std::ptr;
+use std::sync::Weak;
+
+/// Thread-safe weak reference to node.
+#[repr(transparent)]
+#[derive(Default)]
+pub struct NwA<T: ?Sized>(Weak<T>);
+
+impl<T> NwA<T> {
+    /// Creates new empty instance.
+    ///
+    /// Calling [`upgrade`] on the return value always gives [`None`].
+    ///
+    /// [`upgrade`]: Self::upgrade
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Weak::new())
+    }
+}
+
+impl<T: ?Sized> NwA<T> {
+    /// Creates reference from base object.
+    #[must_use]
+    pub fn as_base(base: &Weak<T>) -> &Self {
+        unsafe { mem::transmute(base) }
+    }
+
+    /// Creates instance from base object.
+    #[must_use]
+    pub fn from_base(base: Weak<T>) -> Self {
+        Self(base)
+    }
+
+    /// Consumes this pointer, returning base object.
+    #[must_use]
+    pub fn into_base(self) -> Weak<T> {
+        self.0
+    }
+
+    /// Returns a raw pointer to the data.
+    ///
+    /// The pointer is valid only if there are some strong references.
+    /// The pointer may be dangling, unaligned or even [`null`] otherwise.
+    ///
+    /// [`null`]: std::ptr::null
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        Weak::as_ptr(&self.0)
+    }
+
+    /// Returns key of this node identity.
+    ///
+    /// The key is valid as long as this pointer is alive,
+    /// even if there are no strong references.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        NodeKey::from_ptr(self.as_ptr())
+    }
+
+    /// Returns `true` if two pointers point to the same node.
+    ///
+    /// Unlike [`Weak::ptr_eq`], pointee types may differ, and pointer
+    /// metadata such as vtables is ignored. As with it, dangling pointers
+    /// created by [`new`](Self::new) are equal to each other.
+    #[must_use]
+    pub fn ptr_eq<U: ?Sized>(&self, other: &NwA<U>) -> bool {
+        ptr::addr_eq(self.as_ptr(), other.as_ptr())
+    }
+
+    /// Compares addresses of two nodes.
+    ///
+    /// Like [`ptr_eq`](Self::ptr_eq), pointee types may differ.
+    #[must_use]
+    pub fn ptr_cmp<U: ?Sized>(&self, other: &NwA<U>) -> Ordering {
+        cmp_ptr(self.as_ptr(), other.as_ptr())
+    }
+
+    /// Returns cycle-aware debug formatter of graph from this node.
+    #[must_use]
+    pub fn debug_graph(&self) -> DebugGraph<'_, Self> {
+        DebugGraph::new(self)
+    }
+
+    /// Consumes this pointer, returning the wrapped pointer.
+    ///
+    /// The pointer is the same as [`as_ptr`], so it can be used as identity.
+    /// To avoid memory leak, the pointer must be converted back
+    /// by [`from_raw`].
+    ///
+    /// [`as_ptr`]: Self::as_ptr
+    /// [`from_raw`]: Self::from_raw
+    #[must_use]
+    pub fn into_raw(self) -> *const T {
+        self.0.into_raw()
+    }
+
+    /// Creates instance from raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Weak::from_raw`]. Typically, `ptr` must have been
+    /// returned by [`into_raw`], and can be converted back only once.
+    ///
+    /// [`Weak::from_raw`]: std::sync::Weak::from_raw
+    /// [`into_raw`]: Self::into_raw
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        Self(unsafe { Weak::from_raw(ptr) })
+    }
+
+    /// Returns base object.
+    #[must_use]
+    pub fn base(&self) -> &Weak<T> {
+        &self.0
+    }
+
+    /// Creates strong pointer to this node.
+    ///
+    /// Returns [`None`] if the inner value has since been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Na<T>> {
+        self.0.upgrade().map(Na::from_base)
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(&self) -> usize {
+        self.0.strong_count()
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(&self) -> usize {
+        self.0.weak_count()
+    }
+}
+
+impl_weak_downcast!(NwA, dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<NwA<U>> for NwA<T> {}
+
+#[cfg(feature = "nightly")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<NwA<U>> for NwA<T> {}
+
+impl<T: ?Sized> Borrow<NodeKey> for NwA<T> {
+    fn borrow(&self) -> &NodeKey {
+        self.key()
+    }
+}
+
+impl<T: ?Sized> Clone for NwA<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for NwA<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.upgrade().filter(|_| Label::is_active()) {
+            Some(x) => x.fmt(f),
+            None => f.debug_tuple("NwA").field(&self.0).finish(),
+        }
+    }
+}
+
+impl<T: ?Sized> Eq for NwA<T> {}
+
+impl<T: ?Sized> From<&Na<T>> for NwA<T> {
+    fn from(value: &Na<T>) -> Self {
+        Na::downgrade(value)
+    }
+}
+
+impl<T: ?Sized> From<Weak<T>> for NwA<T> {
+    fn from(value: Weak<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> From<NwA<T>> for Weak<T> {
+    fn from(value: NwA<T>) -> Self {
+        value.into_base()
+    }
+}
+
+impl<T: ?Sized> Hash for NwA<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for NwA<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_ptr(self.as_ptr(), other.as_ptr())
+    }
+}
+
+impl<T: ?Sized> PartialEq for NwA<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ptr_eq(&other.0)
+    }
+}
+
+impl<T: ?Sized> PartialEq<Na<T>> for NwA<T> {
+    fn eq(&self, other: &Na<T>) -> bool {
+        Na::ptr_eq_weak(other, self)
+    }
+}
+
+impl<T: ?Sized> PartialOrd for NwA<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> PartialOrd<Na<T>> for NwA<T> {
+    fn partial_cmp(&self, other: &Na<T>) -> Option<Ordering> {
+        Some(cmp_ptr(self.as_ptr(), Na::as_ptr(other)))
+    }
+}
@@ -0,0 +1,170 @@
+//! Provider of [`PinNr`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use crate::{DebugGraph, PinNw};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::pin::Pin;
+
+/// Pinned strong reference to node.
+///
+/// This type is like `Pin<Nr<T>>`, but comparison is based on location
+/// like [`Nr`]. And, weak pointers to the node ([`PinNw`]) can be upgraded
+/// to this type again.
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::PinNr;
+/// # use easy_node::prelude::*;
+/// # use std::collections::HashSet;
+/// # use std::marker::PhantomPinned;
+/// let node = Nr::pin((42, PhantomPinned));
+/// let nw = PinNr::downgrade(&node);
+/// let set = HashSet::from([node.clone()]);
+/// assert!(set.contains(&nw.upgrade().unwrap()));
+/// ```
+pub struct PinNr<T: ?Sized>(Nr<T>);
+
+impl<T: ?Sized + Unpin> PinNr<T> {
+    /// Creates a new instance from unpin node.
+    #[must_use]
+    pub fn new(node: Nr<T>) -> Self {
+        Self(node)
+    }
+
+    /// Consumes this pointer, returning unpinned node.
+    #[must_use]
+    pub fn into_nr(this: Self) -> Nr<T> {
+        this.0
+    }
+}
+
+impl<T: ?Sized> PinNr<T> {
+    /// Creates a new instance from node.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Pin::new_unchecked`]. Node must not be moved out from
+    /// any pointer to it (including pointers created in the future).
+    #[must_use]
+    pub unsafe fn new_unchecked(node: Nr<T>) -> Self {
+        Self(node)
+    }
+
+    /// Returns pinned reference to the value.
+    #[must_use]
+    pub fn as_pin(this: &Self) -> Pin<&T> {
+        unsafe { Pin::new_unchecked(&this.0) }
+    }
+
+    /// Consumes this pointer, returning standard pinned pointer.
+    #[must_use]
+    pub fn into_pin(this: Self) -> Pin<Nr<T>> {
+        unsafe { Pin::new_unchecked(this.0) }
+    }
+
+    /// Returns a raw pointer to the data.
+    #[must_use]
+    pub fn as_ptr(this: &Self) -> *const T {
+        Nr::as_ptr(&this.0)
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(this: &Self) -> &NodeKey {
+        Nr::key(&this.0)
+    }
+
+    /// Returns cycle-aware debug formatter of graph from this node.
+    #[must_use]
+    pub fn debug_graph(this: &Self) -> DebugGraph<'_, Self> {
+        DebugGraph::new(this)
+    }
+
+    /// Creates weak pointer to this node.
+    #[must_use]
+    pub fn downgrade(this: &Self) -> PinNw<T> {
+        unsafe { PinNw::from_nw(Nr::downgrade(&this.0)) }
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(this: &Self) -> usize {
+        Nr::strong_count(&this.0)
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(this: &Self) -> usize {
+        Nr::weak_count(&this.0)
+    }
+}
+
+impl<T: ?Sized> Borrow<NodeKey> for PinNr<T> {
+    fn borrow(&self) -> &NodeKey {
+        Self::key(self)
+    }
+}
+
+impl<T: ?Sized> Clone for PinNr<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for PinNr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("PinNr", Self::key(self), &**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for PinNr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized + Display> Display for PinNr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ?Sized> Eq for PinNr<T> {}
+
+impl<T: ?Sized> From<PinNr<T>> for Pin<Nr<T>> {
+    fn from(value: PinNr<T>) -> Self {
+        PinNr::into_pin(value)
+    }
+}
+
+impl<T: ?Sized> Hash for PinNr<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Self::key(self).hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for PinNr<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: ?Sized> PartialEq for PinNr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: ?Sized> PartialOrd for PinNr<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
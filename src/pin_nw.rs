@@ -0,0 +1,108 @@
+//! Provider of [`PinNw`].
+
+use crate::PinNr;
+use crate::debug_graph::*;
+use crate::prelude::*;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+/// Weak reference to pinned node.
+///
+/// This type is created from [`PinNr`], and can be upgraded to it again.
+/// Comparison is based on location like [`Nw`].
+pub struct PinNw<T: ?Sized>(Nw<T>);
+
+impl<T: ?Sized> PinNw<T> {
+    /// Creates instance from weak pointer to pinned node.
+    ///
+    /// # Safety
+    ///
+    /// The node must be pinned (see [`PinNr::new_unchecked`]).
+    pub(crate) unsafe fn from_nw(nw: Nw<T>) -> Self {
+        Self(nw)
+    }
+
+    /// Returns a raw pointer to the data.
+    ///
+    /// The pointer is valid only if there are some strong references.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(&self) -> &NodeKey {
+        self.0.key()
+    }
+
+    /// Attempts to upgrade to pinned strong pointer.
+    ///
+    /// Returns [`None`] if the inner value has since been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<PinNr<T>> {
+        let node = self.0.upgrade()?;
+        Some(unsafe { PinNr::new_unchecked(node) })
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(&self) -> usize {
+        self.0.strong_count()
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(&self) -> usize {
+        self.0.weak_count()
+    }
+}
+
+impl<T: ?Sized> Borrow<NodeKey> for PinNw<T> {
+    fn borrow(&self) -> &NodeKey {
+        self.key()
+    }
+}
+
+impl<T: ?Sized> Clone for PinNw<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for PinNw<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.upgrade().filter(|_| Label::is_active()) {
+            Some(x) => x.fmt(f),
+            None => f.debug_tuple("PinNw").field(self.0.base()).finish(),
+        }
+    }
+}
+
+impl<T: ?Sized> Eq for PinNw<T> {}
+
+impl<T: ?Sized> Hash for PinNw<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for PinNw<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: ?Sized> PartialEq for PinNw<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: ?Sized> PartialOrd for PinNw<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
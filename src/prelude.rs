@@ -1,4 +1,11 @@
 //! Crate’s prelude.
 
+pub use crate::Link;
+pub use crate::Na;
+pub use crate::Neighbors;
+pub use crate::NodeKey;
 pub use crate::Nr;
 pub use crate::Nw;
+pub use crate::NwA;
+pub use crate::SeqNr;
+pub use crate::SeqNw;
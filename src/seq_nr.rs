@@ -0,0 +1,143 @@
+//! Provider of [`SeqNr`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::atomic::{self, AtomicU64};
+
+static NEXT_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Strong reference to node with creation order.
+///
+/// This type is like [`Nr`], but each node is stamped with
+/// a monotonic sequence number at creation.
+///
+/// Equality is based on location (same as [`Nr`]).
+/// However, hash and order are based on sequence number.
+/// So, iteration order of [`BTreeSet`] etc. is reproducible.
+///
+/// [`BTreeSet`]: std::collections::BTreeSet
+pub struct SeqNr<T: ?Sized>(Nr<T>, u64);
+
+impl<T> SeqNr<T> {
+    /// Creates a new instance.
+    pub fn new(value: T) -> Self {
+        Self(Nr::new(value), next_seq())
+    }
+
+    /// Creates self-referencing instance.
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&SeqNw<T>) -> T,
+    {
+        let seq = next_seq();
+        let base = Nr::new_cyclic(|w| data_fn(&SeqNw::from_parts(w.clone(), seq)));
+        Self(base, seq)
+    }
+}
+
+impl<T: ?Sized> SeqNr<T> {
+    /// Returns sequence number of this node.
+    #[must_use]
+    pub fn seq(this: &Self) -> u64 {
+        this.1
+    }
+
+    /// Returns a raw pointer to the data.
+    #[must_use]
+    pub fn as_ptr(this: &Self) -> *const T {
+        Nr::as_ptr(&this.0)
+    }
+
+    /// Returns base object.
+    #[must_use]
+    pub fn base(this: &Self) -> &Nr<T> {
+        &this.0
+    }
+
+    /// Creates weak pointer to this node.
+    #[must_use]
+    pub fn downgrade(this: &Self) -> SeqNw<T> {
+        SeqNw::from_parts(Nr::downgrade(&this.0), this.1)
+    }
+
+    /// Returns the number of strong pointer to this node.
+    pub fn strong_count(this: &Self) -> usize {
+        Nr::strong_count(&this.0)
+    }
+
+    /// Returns the number of weak pointer to this node.
+    pub fn weak_count(this: &Self) -> usize {
+        Nr::weak_count(&this.0)
+    }
+
+    pub(crate) fn from_parts(base: Nr<T>, seq: u64) -> Self {
+        Self(base, seq)
+    }
+}
+
+impl<T: ?Sized> Clone for SeqNr<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for SeqNr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("SeqNr", Nr::key(&self.0), &**self, f)
+    }
+}
+
+impl<T: Default> Default for SeqNr<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Deref for SeqNr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl<T: ?Sized + Display> Display for SeqNr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ?Sized> Eq for SeqNr<T> {}
+
+impl<T: ?Sized> Hash for SeqNr<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for SeqNr<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<T: ?Sized> PartialEq for SeqNr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: ?Sized> PartialOrd for SeqNr<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns next sequence number.
+fn next_seq() -> u64 {
+    NEXT_SEQ.fetch_add(1, atomic::Ordering::Relaxed)
+}
@@ -0,0 +1,121 @@
+//! Provider of [`SeqNw`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+/// Weak reference to node with creation order.
+///
+/// This type is weak version of [`SeqNr`].
+pub struct SeqNw<T: ?Sized>(Nw<T>, u64);
+
+impl<T> SeqNw<T> {
+    /// Creates new empty instance.
+    ///
+    /// Calling [`upgrade`] on the return value always gives [`None`].
+    /// And, sequence number of the return value is [`u64::MAX`].
+    ///
+    /// [`upgrade`]: Self::upgrade
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Nw::new(), u64::MAX)
+    }
+}
+
+impl<T: ?Sized> SeqNw<T> {
+    /// Returns sequence number of this node.
+    #[must_use]
+    pub fn seq(&self) -> u64 {
+        self.1
+    }
+
+    /// Returns a raw pointer to the data.
+    ///
+    /// The pointer is valid only if there are some strong references.
+    /// The pointer may be dangling, unaligned or even [`null`] otherwise.
+    ///
+    /// [`null`]: std::ptr::null
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
+
+    /// Returns base object.
+    #[must_use]
+    pub fn base(&self) -> &Nw<T> {
+        &self.0
+    }
+
+    /// Creates strong pointer to this node.
+    ///
+    /// Returns [`None`] if the inner value has since been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<SeqNr<T>> {
+        Some(SeqNr::from_parts(self.0.upgrade()?, self.1))
+    }
+
+    /// Returns the number of strong pointer to this node.
+    #[must_use]
+    pub fn strong_count(&self) -> usize {
+        self.0.strong_count()
+    }
+
+    /// Returns the number of weak pointer to this node.
+    #[must_use]
+    pub fn weak_count(&self) -> usize {
+        self.0.weak_count()
+    }
+
+    pub(crate) fn from_parts(base: Nw<T>, seq: u64) -> Self {
+        Self(base, seq)
+    }
+}
+
+impl<T: ?Sized> Clone for SeqNw<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for SeqNw<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.upgrade().filter(|_| Label::is_active()) {
+            Some(x) => x.fmt(f),
+            None => f.debug_tuple("SeqNw").field(self.0.base()).finish(),
+        }
+    }
+}
+
+impl<T> Default for SeqNw<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Eq for SeqNw<T> {}
+
+impl<T: ?Sized> Hash for SeqNw<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+impl<T: ?Sized> Ord for SeqNw<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<T: ?Sized> PartialEq for SeqNw<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: ?Sized> PartialOrd for SeqNw<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,339 @@
+//! Provider of [`SerdeGraph`] and serde support of node pointers.
+
+use crate::prelude::*;
+use serde::de::{self, EnumAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{self, SerializeTupleVariant};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+use std::thread::LocalKey;
+
+const NAME: &str = "Node";
+const VARIANTS: &[&str] = &["Def", "Ref", "Dead"];
+
+thread_local! {
+    static IDS: RefCell<Option<HashMap<*const (), u64>>> = const { RefCell::new(None) };
+    static SLOTS: RefCell<Option<HashMap<u64, Box<dyn Any>>>> = const { RefCell::new(None) };
+}
+
+/// Scope of node sharing for serialization and deserialization.
+///
+/// While (de)serializing with this type, each node is written only once
+/// with id, and later pointers to the same node are written as the id.
+/// So, sharing and cycles of nodes are preserved.
+///
+/// Node pointers outside this type have their own scope. In that case,
+/// sharing is preserved only within each pointer.
+///
+/// # Format
+///
+/// Node pointers are written as enum with following variants.
+///
+/// - `Def(id, value)` - First appearance of the node.
+/// - `Ref(id)` - Repeated appearance of the node.
+/// - `Dead` - Weak pointer to dropped node.
+///
+/// # Cycles
+///
+/// Strong reference cycles can not be deserialized. Weak pointers to
+/// a node inside its own definition (e.g. parent pointers of children)
+/// are rebuilt by [`Nr::try_new_cyclic`].
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::SerdeGraph;
+/// # use easy_node::prelude::*;
+/// let nr = Nr::new(42);
+/// let json = serde_json::to_string(&SerdeGraph([nr.clone(), nr])).unwrap();
+/// assert_eq!(json, r#"[{"Def":[0,42]},{"Ref":0}]"#);
+///
+/// let result = serde_json::from_str::<SerdeGraph<[Nr<i32>; 2]>>(&json);
+/// let [x, y] = result.unwrap().0;
+/// assert_eq!(x, y);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SerdeGraph<T>(pub T);
+
+impl<T: Serialize> Serialize for SerdeGraph<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let _scope = Scope::enter(&IDS);
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SerdeGraph<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let _scope = Scope::enter(&SLOTS);
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for Nr<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let _scope = Scope::enter(&IDS);
+        serialize_node(self, serializer)
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for Nw<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let _scope = Scope::enter(&IDS);
+        match self.upgrade() {
+            Some(x) => serialize_node(&x, serializer),
+            None => serializer.serialize_unit_variant(NAME, 2, "Dead"),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> Deserialize<'de> for Nr<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let _scope = Scope::enter(&SLOTS);
+        let parsed = deserializer.deserialize_enum(NAME, VARIANTS, NodeVisitor::new())?;
+        let base = match parsed {
+            Parsed::Def(x) => x,
+            Parsed::Ref(id) => match Slot::<T>::get(id).map_err(de::Error::custom)? {
+                Slot::Done(x) => x,
+                Slot::Building(_) => return Err(de::Error::custom(STRONG_CYCLE)),
+            },
+            Parsed::Dead => return Err(de::Error::custom(DEAD_NODE)),
+        };
+
+        Ok(Nr::from_base(base))
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> Deserialize<'de> for Nw<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let _scope = Scope::enter(&SLOTS);
+        let parsed = deserializer.deserialize_enum(NAME, VARIANTS, NodeVisitor::new())?;
+        let base = match parsed {
+            Parsed::Def(x) => Rc::downgrade(&x),
+            Parsed::Ref(id) => match Slot::<T>::get(id).map_err(de::Error::custom)? {
+                Slot::Done(x) => Rc::downgrade(&x),
+                Slot::Building(x) => x,
+            },
+            Parsed::Dead => Weak::new(),
+        };
+
+        Ok(Nw::from_base(base))
+    }
+}
+
+const STRONG_CYCLE: &str = "strong reference cycle can not be deserialized";
+const DEAD_NODE: &str = "dead node can not be deserialized as strong pointer";
+
+/// Serializes node with id.
+fn serialize_node<T, S>(node: &Nr<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + Serialize,
+    S: Serializer,
+{
+    let addr = Nr::key(node).addr();
+    let id = IDS.with_borrow_mut(|ids| {
+        let ids = ids.as_mut().ok_or_else(|| ser::Error::custom("no scope"))?;
+        let next = ids.len() as u64;
+        Ok(match ids.entry(addr) {
+            Entry::Occupied(e) => Err(*e.get()),
+            Entry::Vacant(e) => Ok(*e.insert(next)),
+        })
+    })?;
+
+    match id {
+        Ok(id) => {
+            let mut state = serializer.serialize_tuple_variant(NAME, 0, "Def", 2)?;
+            state.serialize_field(&id)?;
+            state.serialize_field(&**node)?;
+            state.end()
+        }
+        Err(id) => serializer.serialize_newtype_variant(NAME, 1, "Ref", &id),
+    }
+}
+
+/// Parsed node pointer.
+enum Parsed<T> {
+    Def(Rc<T>),
+    Ref(u64),
+    Dead,
+}
+
+/// Deserialized node registered to scope.
+enum Slot<T> {
+    /// Node under construction.
+    Building(Weak<T>),
+    /// Constructed node.
+    Done(Rc<T>),
+}
+
+impl<T: 'static> Slot<T> {
+    fn get(id: u64) -> Result<Self, String> {
+        SLOTS.with_borrow(|slots| {
+            let slots = slots.as_ref().ok_or("no scope")?;
+            let slot = slots.get(&id).ok_or(format!("unknown node id {id}"))?;
+            let slot = slot.downcast_ref::<Self>();
+            let slot = slot.ok_or(format!("type mismatch of node id {id}"))?;
+            Ok(slot.clone())
+        })
+    }
+
+    fn set(id: u64, slot: Self) -> Result<(), String> {
+        SLOTS.with_borrow_mut(|slots| {
+            let slots = slots.as_mut().ok_or("no scope")?;
+            let is_building = matches!(slot, Self::Building(_));
+            let old = slots.insert(id, Box::new(slot));
+            match old.is_some() && is_building {
+                true => Err(format!("duplicate node id {id}")),
+                false => Ok(()),
+            }
+        })
+    }
+}
+
+impl<T> Clone for Slot<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Building(x) => Self::Building(x.clone()),
+            Self::Done(x) => Self::Done(x.clone()),
+        }
+    }
+}
+
+/// Tag of node pointer enum.
+enum Tag {
+    Def,
+    Ref,
+    Dead,
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+struct TagVisitor;
+
+impl Visitor<'_> for TagVisitor {
+    type Value = Tag;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("node variant")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        match v {
+            0 => Ok(Tag::Def),
+            1 => Ok(Tag::Ref),
+            2 => Ok(Tag::Dead),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v {
+            "Def" => Ok(Tag::Def),
+            "Ref" => Ok(Tag::Ref),
+            "Dead" => Ok(Tag::Dead),
+            _ => Err(E::unknown_variant(v, VARIANTS)),
+        }
+    }
+}
+
+struct NodeVisitor<T>(PhantomData<T>);
+
+impl<T> NodeVisitor<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> Visitor<'de> for NodeVisitor<T> {
+    type Value = Parsed<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("node pointer")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (tag, variant) = data.variant::<Tag>()?;
+        match tag {
+            Tag::Def => variant.tuple_variant(2, DefVisitor::new()).map(Parsed::Def),
+            Tag::Ref => variant.newtype_variant().map(Parsed::Ref),
+            Tag::Dead => variant.unit_variant().map(|_| Parsed::Dead),
+        }
+    }
+}
+
+struct DefVisitor<T>(PhantomData<T>);
+
+impl<T> DefVisitor<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> Visitor<'de> for DefVisitor<T> {
+    type Value = Rc<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("node id and value")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let id = seq.next_element::<u64>()?;
+        let id = id.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+        let node = Nr::try_new_cyclic(|w| self.read_value(id, w.base().clone(), &mut seq))?;
+
+        let base = Nr::into_base(node);
+        Slot::set(id, Slot::Done(base.clone())).map_err(de::Error::custom)?;
+        Ok(base)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> DefVisitor<T> {
+    /// Reads node value, registering the node as under construction.
+    fn read_value<A>(&self, id: u64, weak: Weak<T>, seq: &mut A) -> Result<T, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        Slot::set(id, Slot::Building(weak)).map_err(de::Error::custom)?;
+        let value = seq.next_element::<T>()?;
+        value.ok_or_else(|| de::Error::invalid_length(1, self))
+    }
+}
+
+/// Scope of thread local context.
+///
+/// Context is created on entering the outermost scope,
+/// and discarded on leaving it.
+struct Scope<C: 'static> {
+    key: &'static LocalKey<RefCell<Option<C>>>,
+    is_owner: bool,
+}
+
+impl<C: Default> Scope<C> {
+    fn enter(key: &'static LocalKey<RefCell<Option<C>>>) -> Self {
+        let is_owner = key.with_borrow_mut(|context| {
+            let is_owner = context.is_none();
+            context.get_or_insert_with(C::default);
+            is_owner
+        });
+
+        Self { key, is_owner }
+    }
+}
+
+impl<C> Drop for Scope<C> {
+    fn drop(&mut self) {
+        if self.is_owner {
+            let context = self.key.with_borrow_mut(Option::take);
+            drop(context);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Provider of [`TreeNode`].
+
+use crate::prelude::*;
+use crate::{Dfs, DfsPostOrder};
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter, Result};
+use std::iter::FusedIterator;
+use std::mem;
+use std::ptr;
+use std::vec;
+
+/// Node of tree with strong child links and weak parent links.
+///
+/// This type is used as `Nr<TreeNode<T>>`. Parent links are maintained
+/// by each mutation method. So, children of a node always have the node
+/// as their parent.
+///
+/// As [`Neighbors`], this type returns children (parent is excluded).
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::TreeNode;
+/// # use easy_node::prelude::*;
+/// let root = Nr::new(TreeNode::new("root"));
+/// let child = Nr::new(TreeNode::new("child"));
+/// TreeNode::append_child(&root, child.clone());
+/// assert_eq!(child.parent(), Some(root.clone()));
+/// assert_eq!(TreeNode::root(&child), root);
+/// ```
+pub struct TreeNode<T> {
+    parent: RefCell<Nw<Self>>,
+    children: RefCell<Vec<Nr<Self>>>,
+    value: T,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a new instance without parent and children.
+    pub fn new(value: T) -> Self {
+        Self {
+            parent: RefCell::new(Nw::new()),
+            children: RefCell::new(Vec::new()),
+            value,
+        }
+    }
+
+    /// Returns the value of this node.
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the parent of this node.
+    ///
+    /// Returns [`None`] if this node is a root.
+    #[must_use]
+    pub fn parent(&self) -> Option<Nr<Self>> {
+        self.parent.borrow().upgrade()
+    }
+
+    /// Returns an iterator over the children of this node.
+    ///
+    /// Children are collected at this call, so the iterator does not keep
+    /// the node borrowed.
+    pub fn children(&self) -> TreeChildren<T> {
+        TreeChildren(self.children.borrow().clone().into_iter())
+    }
+
+    /// Returns an iterator over the ancestors of this node.
+    ///
+    /// The iterator starts from the parent and ends with the root.
+    pub fn ancestors(&self) -> TreeAncestors<T> {
+        TreeAncestors(self.parent())
+    }
+
+    /// Returns the root of the tree containing the node.
+    #[must_use]
+    pub fn root(this: &Nr<Self>) -> Nr<Self> {
+        this.ancestors().last().unwrap_or_else(|| this.clone())
+    }
+
+    /// Returns an iterator over the node and its descendants in pre-order.
+    #[must_use]
+    pub fn descendants(this: &Nr<Self>) -> Dfs<Self> {
+        Dfs::new(this)
+    }
+
+    /// Returns an iterator over the node and its descendants in post-order.
+    #[must_use]
+    pub fn descendants_post_order(this: &Nr<Self>) -> DfsPostOrder<Self> {
+        DfsPostOrder::new(this)
+    }
+
+    /// Appends the child as the last child of the node.
+    ///
+    /// If the child already has a parent, it is detached first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child is the node itself or its ancestor.
+    pub fn append_child(this: &Nr<Self>, child: Nr<Self>) {
+        Self::adopt(this, &child);
+        this.children.borrow_mut().push(child);
+    }
+
+    /// Inserts the child before the reference child of the node.
+    ///
+    /// If the child already has a parent, it is detached first.
+    /// If the child is the reference child, nothing happens.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the reference child is not a child of the node, or
+    /// if the child is the node itself or its ancestor.
+    pub fn insert_before(this: &Nr<Self>, child: Nr<Self>, reference: &Nr<Self>) {
+        let is_child = *reference.parent.borrow() == *this;
+        assert!(is_child, "Reference node must be child of the node.");
+        if child == *reference {
+            return;
+        }
+
+        Self::adopt(this, &child);
+        let mut children = this.children.borrow_mut();
+        let index = children.iter().position(|x| x == reference).unwrap();
+        children.insert(index, child);
+    }
+
+    /// Detaches this node from its parent.
+    ///
+    /// Descendants of this node are kept as they are.
+    pub fn detach(&self) {
+        let parent = self.parent.replace(Nw::new()).upgrade();
+        if let Some(parent) = parent {
+            let mut children = parent.children.borrow_mut();
+            children.retain(|x| !ptr::eq(Nr::as_ptr(x), self));
+        }
+    }
+
+    /// Prepares the child to be added to the node.
+    fn adopt(this: &Nr<Self>, child: &Nr<Self>) {
+        let is_cyclic = *child == *this || this.ancestors().any(|x| x == *child);
+        assert!(!is_cyclic, "Child must not be the node or its ancestor.");
+        child.detach();
+        *child.parent.borrow_mut() = Nr::downgrade(this);
+    }
+}
+
+impl<T: Debug> Debug for TreeNode<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("TreeNode")
+            .field("value", &self.value)
+            .field("children", &self.children.borrow())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for TreeNode<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Drop for TreeNode<T> {
+    fn drop(&mut self) {
+        // Drop descendants iteratively to avoid stack overflow on deep trees.
+        let mut nodes = mem::take(self.children.get_mut());
+        while let Some(node) = nodes.pop() {
+            if Nr::strong_count(&node) == 1 {
+                nodes.append(&mut node.children.borrow_mut());
+            }
+        }
+    }
+}
+
+impl<T> From<T> for TreeNode<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Neighbors for TreeNode<T> {
+    fn neighbors(&self) -> impl Iterator<Item = Link<Self>> {
+        self.children().map(Link::Strong)
+    }
+}
+
+/// Iterator over children of [`TreeNode`].
+#[derive(Debug)]
+pub struct TreeChildren<T>(vec::IntoIter<Nr<TreeNode<T>>>);
+
+impl<T> Iterator for TreeChildren<T> {
+    type Item = Nr<TreeNode<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for TreeChildren<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for TreeChildren<T> {}
+
+impl<T> FusedIterator for TreeChildren<T> {}
+
+/// Iterator over ancestors of [`TreeNode`].
+#[derive(Debug)]
+pub struct TreeAncestors<T>(Option<Nr<TreeNode<T>>>);
+
+impl<T> Iterator for TreeAncestors<T> {
+    type Item = Nr<TreeNode<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self.0.take()?;
+        self.0 = ret.parent();
+        Some(ret)
+    }
+}
+
+impl<T> FusedIterator for TreeAncestors<T> {}
@@ -0,0 +1,87 @@
+//! Provider of [`UniqueNa`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result};
+use std::ops::{Deref, DerefMut};
+use std::sync::UniqueArc;
+
+/// Thread-safe uniquely owned node, which can be frozen into [`Na`].
+///
+/// Weak pointers to the node can be created from this pointer, but they
+/// can not be upgraded until this pointer is frozen by [`into_na`].
+/// So, the node can be mutated freely while it is unique. Frozen node has
+/// the same identity, and weak pointers created before then refer to it.
+///
+/// This type requires `nightly` feature.
+///
+/// [`into_na`]: Self::into_na
+pub struct UniqueNa<T: ?Sized>(UniqueArc<T>);
+
+impl<T> UniqueNa<T> {
+    /// Creates a new instance.
+    pub fn new(value: T) -> Self {
+        Self(UniqueArc::new(value))
+    }
+}
+
+impl<T: ?Sized> UniqueNa<T> {
+    /// Returns a raw pointer to the data.
+    #[must_use]
+    pub fn as_ptr(this: &Self) -> *const T {
+        &**this
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(this: &Self) -> &NodeKey {
+        NodeKey::from_ptr(Self::as_ptr(this))
+    }
+
+    /// Creates a new weak pointer to this node.
+    ///
+    /// The weak pointer can not be upgraded until this pointer is frozen.
+    #[must_use]
+    pub fn downgrade(this: &Self) -> NwA<T> {
+        NwA::from_base(UniqueArc::downgrade(&this.0))
+    }
+
+    /// Freezes this pointer into shared node with the same identity.
+    #[must_use]
+    pub fn into_na(this: Self) -> Na<T> {
+        Na::from_base(UniqueArc::into_arc(this.0))
+    }
+}
+
+impl<T: ?Sized> Borrow<NodeKey> for UniqueNa<T> {
+    fn borrow(&self) -> &NodeKey {
+        Self::key(self)
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for UniqueNa<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("UniqueNa", Self::key(self), &**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for UniqueNa<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for UniqueNa<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> From<UniqueNa<T>> for Na<T> {
+    fn from(value: UniqueNa<T>) -> Self {
+        UniqueNa::into_na(value)
+    }
+}
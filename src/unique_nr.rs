@@ -0,0 +1,87 @@
+//! Provider of [`UniqueNr`].
+
+use crate::debug_graph::*;
+use crate::prelude::*;
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result};
+use std::ops::{Deref, DerefMut};
+use std::rc::UniqueRc;
+
+/// Uniquely owned node, which can be frozen into [`Nr`].
+///
+/// Weak pointers to the node can be created from this pointer, but they
+/// can not be upgraded until this pointer is frozen by [`into_nr`].
+/// So, the node can be mutated freely while it is unique. Frozen node has
+/// the same identity, and weak pointers created before then refer to it.
+///
+/// This type requires `nightly` feature.
+///
+/// [`into_nr`]: Self::into_nr
+pub struct UniqueNr<T: ?Sized>(UniqueRc<T>);
+
+impl<T> UniqueNr<T> {
+    /// Creates a new instance.
+    pub fn new(value: T) -> Self {
+        Self(UniqueRc::new(value))
+    }
+}
+
+impl<T: ?Sized> UniqueNr<T> {
+    /// Returns a raw pointer to the data.
+    #[must_use]
+    pub fn as_ptr(this: &Self) -> *const T {
+        &**this
+    }
+
+    /// Returns key of this node identity.
+    #[must_use]
+    pub fn key(this: &Self) -> &NodeKey {
+        NodeKey::from_ptr(Self::as_ptr(this))
+    }
+
+    /// Creates a new weak pointer to this node.
+    ///
+    /// The weak pointer can not be upgraded until this pointer is frozen.
+    #[must_use]
+    pub fn downgrade(this: &Self) -> Nw<T> {
+        Nw::from_base(UniqueRc::downgrade(&this.0))
+    }
+
+    /// Freezes this pointer into shared node with the same identity.
+    #[must_use]
+    pub fn into_nr(this: Self) -> Nr<T> {
+        Nr::from_base(UniqueRc::into_rc(this.0))
+    }
+}
+
+impl<T: ?Sized> Borrow<NodeKey> for UniqueNr<T> {
+    fn borrow(&self) -> &NodeKey {
+        Self::key(self)
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for UniqueNr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_node("UniqueNr", Self::key(self), &**self, f)
+    }
+}
+
+impl<T: ?Sized> Deref for UniqueNr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for UniqueNr<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> From<UniqueNr<T>> for Nr<T> {
+    fn from(value: UniqueNr<T>) -> Self {
+        UniqueNr::into_nr(value)
+    }
+}
@@ -0,0 +1,76 @@
+//! Provider of unsizing macros.
+
+/// Converts [`Nr`] to unsized type (like `Nr<dyn Trait>`).
+///
+/// This macro is a workaround for that `CoerceUnsized` is unstable.
+///
+/// [`Nr`]: crate::Nr
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::nr_unsize;
+/// # use easy_node::prelude::*;
+/// # use std::fmt::Display;
+/// let nr = Nr::new(42);
+/// let result = nr_unsize!(nr.clone(), dyn Display);
+/// assert_eq!(result.to_string(), "42");
+/// assert_eq!(Nr::as_ptr(&result).cast(), Nr::as_ptr(&nr));
+/// ```
+#[macro_export]
+macro_rules! nr_unsize {
+    ($node:expr, $ty:ty $(,)?) => {{
+        let base = $crate::Nr::into_base($node);
+        $crate::Nr::<$ty>::from_base(base)
+    }};
+}
+
+/// Converts [`Nw`] to unsized type (like `Nw<dyn Trait>`).
+///
+/// This macro is a workaround for that `CoerceUnsized` is unstable.
+///
+/// [`Nw`]: crate::Nw
+///
+/// # Examples
+///
+/// ```
+/// # use easy_node::nw_unsize;
+/// # use easy_node::prelude::*;
+/// # use std::fmt::Display;
+/// let nr = Nr::new(42);
+/// let result = nw_unsize!(Nr::downgrade(&nr), dyn Display);
+/// assert_eq!(result.upgrade().unwrap().to_string(), "42");
+/// ```
+#[macro_export]
+macro_rules! nw_unsize {
+    ($node:expr, $ty:ty $(,)?) => {{
+        let base = $crate::Nw::into_base($node);
+        $crate::Nw::<$ty>::from_base(base)
+    }};
+}
+
+/// Converts [`Na`] to unsized type (like `Na<dyn Trait>`).
+///
+/// This macro is a workaround for that `CoerceUnsized` is unstable.
+///
+/// [`Na`]: crate::Na
+#[macro_export]
+macro_rules! na_unsize {
+    ($node:expr, $ty:ty $(,)?) => {{
+        let base = $crate::Na::into_base($node);
+        $crate::Na::<$ty>::from_base(base)
+    }};
+}
+
+/// Converts [`NwA`] to unsized type (like `NwA<dyn Trait>`).
+///
+/// This macro is a workaround for that `CoerceUnsized` is unstable.
+///
+/// [`NwA`]: crate::NwA
+#[macro_export]
+macro_rules! nwa_unsize {
+    ($node:expr, $ty:ty $(,)?) => {{
+        let base = $crate::NwA::into_base($node);
+        $crate::NwA::<$ty>::from_base(base)
+    }};
+}
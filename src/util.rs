@@ -1,5 +1,6 @@
 //! Crate's utility.
 
+use crate::prelude::*;
 use std::cmp::Ordering;
 
 /// Compare two pointers.
@@ -8,3 +9,55 @@ pub fn cmp_ptr<X: ?Sized, Y: ?Sized>(x: *const X, y: *const Y) -> Ordering {
     let y = y.cast::<()>();
     x.cmp(&y)
 }
+
+/// Returns live neighbor nodes of the node.
+pub fn live_neighbors<T>(node: &Nr<T>) -> impl Iterator<Item = Nr<T>>
+where
+    T: Neighbors + ?Sized,
+{
+    node.neighbors().filter_map(|x| x.upgrade())
+}
+
+/// Implements `downcast` method of strong pointers to `dyn Any`.
+macro_rules! impl_strong_downcast {
+    ($ty:ident, $($dyn:ty),+) => {$(
+        impl $ty<$dyn> {
+            /// Attempts to downcast the node to a concrete type.
+            ///
+            /// The returned pointer refers the same node (identity is preserved).
+            /// If the type is not matched, this pointer is returned as [`Err`].
+            pub fn downcast<T: std::any::Any>(self) -> std::result::Result<$ty<T>, Self> {
+                if !(*self).is::<T>() {
+                    return Err(self);
+                }
+
+                let ptr = $ty::into_raw(self).cast::<T>();
+                Ok(unsafe { $ty::from_raw(ptr) })
+            }
+        }
+    )+};
+}
+
+/// Implements `downcast` method of weak pointers to `dyn Any`.
+macro_rules! impl_weak_downcast {
+    ($ty:ident, $($dyn:ty),+) => {$(
+        impl $ty<$dyn> {
+            /// Attempts to downcast the node to a concrete type.
+            ///
+            /// The returned pointer refers the same node (identity is preserved).
+            /// If the type is not matched, or the node has been dropped (type
+            /// can not be checked), this pointer is returned as [`Err`].
+            pub fn downcast<T: std::any::Any>(self) -> std::result::Result<$ty<T>, Self> {
+                if !self.upgrade().is_some_and(|x| (*x).is::<T>()) {
+                    return Err(self);
+                }
+
+                let ptr = self.into_raw().cast::<T>();
+                Ok(unsafe { $ty::from_raw(ptr) })
+            }
+        }
+    )+};
+}
+
+pub(crate) use impl_strong_downcast;
+pub(crate) use impl_weak_downcast;
@@ -0,0 +1,119 @@
+//! Provider of [`WeakNodeSet`].
+
+use crate::prelude::*;
+use std::collections::HashSet;
+use std::collections::hash_set;
+
+/// Set of weak references to nodes.
+///
+/// Entries are identified by node location (same as [`Nw`] comparison).
+/// Entries whose node has been dropped are purged lazily on insertion and
+/// iteration, or explicitly by [`purge`].
+///
+/// [`purge`]: Self::purge
+#[derive(Debug)]
+pub struct WeakNodeSet<T: ?Sized> {
+    items: HashSet<Nw<T>>,
+    reclaimed: usize,
+}
+
+impl<T: ?Sized> WeakNodeSet<T> {
+    /// Creates a new empty instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            items: HashSet::new(),
+            reclaimed: 0,
+        }
+    }
+
+    /// Returns the number of live nodes in this set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.iter().filter(|x| x.strong_count() > 0).count()
+    }
+
+    /// Returns `true` if this set contains no live nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(|x| x.strong_count() == 0)
+    }
+
+    /// Returns the total number of dead entries reclaimed so far.
+    #[must_use]
+    pub fn reclaimed(&self) -> usize {
+        self.reclaimed
+    }
+
+    /// Returns `true` if this set contains the node.
+    #[must_use]
+    pub fn contains(&self, node: &Nr<T>) -> bool {
+        self.items.contains(Nr::key(node))
+    }
+
+    /// Adds node to this set.
+    ///
+    /// Returns `true` if the node was not already in this set.
+    pub fn insert(&mut self, node: &Nr<T>) -> bool {
+        self.purge();
+        self.items.insert(Nr::downgrade(node))
+    }
+
+    /// Removes node from this set.
+    ///
+    /// Returns `true` if the node was in this set.
+    pub fn remove(&mut self, node: &Nr<T>) -> bool {
+        self.items.remove(Nr::key(node))
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Removes dead entries.
+    ///
+    /// Returns the number of removed entries.
+    pub fn purge(&mut self) -> usize {
+        let old_len = self.items.len();
+        self.items.retain(|x| x.strong_count() > 0);
+        let count = old_len - self.items.len();
+        self.reclaimed += count;
+        count
+    }
+
+    /// Returns an iterator over live nodes.
+    ///
+    /// Dead entries are purged before iteration.
+    pub fn iter(&mut self) -> WeakNodeSetIter<'_, T> {
+        self.purge();
+        WeakNodeSetIter(self.items.iter())
+    }
+}
+
+impl<T: ?Sized> Default for WeakNodeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for &'a mut WeakNodeSet<T> {
+    type Item = Nr<T>;
+    type IntoIter = WeakNodeSetIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over live nodes of [`WeakNodeSet`].
+#[derive(Debug)]
+pub struct WeakNodeSetIter<'a, T: ?Sized>(hash_set::Iter<'a, Nw<T>>);
+
+impl<T: ?Sized> Iterator for WeakNodeSetIter<'_, T> {
+    type Item = Nr<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(Nw::upgrade)
+    }
+}
@@ -0,0 +1,145 @@
+use easy_node::prelude::*;
+use easy_node::{AnyNr, AnyNw};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+#[test]
+fn new() {
+    // Arrange.
+    let nr = Nr::new(42);
+    // Act.
+    let result = AnyNr::new(nr.clone());
+    // Assert.
+    assert_eq!(result.key(), Nr::key(&nr));
+    assert_eq!(result.strong_count(), 2);
+}
+
+#[test]
+fn is() {
+    // Arrange.
+    let target = AnyNr::new(Nr::new(42));
+    // Act.
+    let result1 = target.is::<i32>();
+    let result2 = target.is::<u32>();
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = AnyNr::new(nr.clone());
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nr);
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = AnyNr::new(nr.clone());
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        assert_eq!(result.unwrap_err().key(), Nr::key(&nr));
+    }
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = AnyNr::new(nr.clone());
+    // Act.
+    let result = target.downgrade();
+    // Assert.
+    assert_eq!(result.key(), Nr::key(&nr));
+    assert!(result.is::<i32>());
+    assert_eq!(target.weak_count(), 1);
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = AnyNr::new(nr.clone());
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, format!("AnyNr({:?})", Nr::as_ptr(&nr)));
+}
+
+#[test]
+fn hash() {
+    // Arrange.
+    let nr1 = Nr::new(42);
+    let nr2 = Nr::new("foo");
+    let mut target = HashSet::new();
+    // Act.
+    target.insert(AnyNr::new(nr1.clone()));
+    target.insert(AnyNr::new(nr1.clone()));
+    target.insert(AnyNr::new(nr2.clone()));
+    // Assert.
+    assert_eq!(target.len(), 2);
+    assert!(target.contains(Nr::key(&nr1)));
+    assert!(target.contains(Nr::key(&nr2)));
+}
+
+#[test]
+fn eq() {
+    with_same();
+    with_diff();
+
+    fn with_same() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target1 = AnyNr::new(nr.clone());
+        let target2 = AnyNr::new(nr);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let target1 = AnyNr::new(Nr::new(42));
+        let target2 = AnyNr::new(Nr::new(42));
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+}
+
+#[test]
+fn cmp() {
+    // Arrange.
+    let nr1 = Nr::new(42);
+    let nr2 = Nr::new("foo");
+    let target1 = AnyNr::new(nr1.clone());
+    let target2 = AnyNr::new(nr2.clone());
+    // Act.
+    let result = target1.cmp(&target2);
+    // Assert.
+    assert_eq!(result, Nr::key(&nr1).cmp(Nr::key(&nr2)));
+    assert_ne!(result, Ordering::Equal);
+}
+
+#[test]
+fn from() {
+    // Arrange.
+    let nr = Nr::new(42);
+    // Act.
+    let result = AnyNr::from(nr.clone());
+    // Assert.
+    assert_eq!(result.key(), Nr::key(&nr));
+    assert_eq!(AnyNw::from(Nr::downgrade(&nr)), result.downgrade());
+}
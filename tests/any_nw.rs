@@ -0,0 +1,147 @@
+use easy_node::AnyNw;
+use easy_node::prelude::*;
+use std::collections::HashSet;
+
+#[test]
+fn new() {
+    // Arrange.
+    let nr = Nr::new(42);
+    // Act.
+    let result = AnyNw::new(Nr::downgrade(&nr));
+    // Assert.
+    assert_eq!(result.key(), Nr::key(&nr));
+    assert_eq!(result.strong_count(), 1);
+    assert_eq!(result.weak_count(), 1);
+}
+
+#[test]
+fn is() {
+    // Arrange.
+    let target = AnyNw::new(Nw::<i32>::new());
+    // Act.
+    let result1 = target.is::<i32>();
+    let result2 = target.is::<u32>();
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+    with_dead();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = AnyNw::new(Nr::downgrade(&nr));
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap().upgrade(), Some(nr));
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = AnyNw::new(Nr::downgrade(&nr));
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        assert_eq!(result.unwrap_err().key(), Nr::key(&nr));
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let nw = Nr::downgrade(&nr);
+        let target = AnyNw::new(nw.clone());
+        drop(nr);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nw);
+    }
+}
+
+#[test]
+fn upgrade() {
+    with_alive();
+    with_dead();
+
+    fn with_alive() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = AnyNw::new(Nr::downgrade(&nr));
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result.unwrap().key(), Nr::key(&nr));
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let target = AnyNw::new(Nr::downgrade(&Nr::new(42)));
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert!(result.is_none());
+    }
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = AnyNw::new(Nr::downgrade(&nr));
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, format!("AnyNw({:?})", Nr::as_ptr(&nr)));
+}
+
+#[test]
+fn hash() {
+    // Arrange.
+    let nr1 = Nr::new(42);
+    let nr2 = Nr::new("foo");
+    let mut target = HashSet::new();
+    // Act.
+    target.insert(AnyNw::new(Nr::downgrade(&nr1)));
+    target.insert(AnyNw::new(Nr::downgrade(&nr1)));
+    target.insert(AnyNw::new(Nr::downgrade(&nr2)));
+    // Assert.
+    assert_eq!(target.len(), 2);
+    assert!(target.contains(Nr::key(&nr1)));
+    assert!(target.contains(Nr::key(&nr2)));
+}
+
+#[test]
+fn eq() {
+    with_same();
+    with_diff();
+
+    fn with_same() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target1 = AnyNw::new(Nr::downgrade(&nr));
+        let target2 = AnyNw::new(Nr::downgrade(&nr));
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let nr1 = Nr::new(42);
+        let nr2 = Nr::new(42);
+        let target1 = AnyNw::new(Nr::downgrade(&nr1));
+        let target2 = AnyNw::new(Nr::downgrade(&nr2));
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use easy_node::prelude::*;
+use std::cell::RefCell;
+
+/// Returns root of graph with shared node, weak back link, and dead weak link.
+pub fn sample_graph() -> Nr<Node> {
+    let a = Node::new("a");
+    let b = Node::new("b");
+    let c = Node::new("c");
+    let d = Node::new("d");
+    let dead = Node::new("dead");
+    a.link([b.clone().into(), c.clone().into()]);
+    b.link([d.clone().into()]);
+    c.link([d.clone().into(), Nr::downgrade(&a).into()]);
+    d.link([Nr::downgrade(&dead).into()]);
+    a
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub name: &'static str,
+    pub links: RefCell<Vec<Link<Node>>>,
+}
+
+impl Node {
+    pub fn new(name: &'static str) -> Nr<Self> {
+        let links = RefCell::new(Vec::new());
+        Nr::new(Self { name, links })
+    }
+
+    pub fn link<const N: usize>(&self, links: [Link<Self>; N]) {
+        self.links.borrow_mut().extend(links);
+    }
+}
+
+impl Neighbors for Node {
+    fn neighbors(&self) -> impl Iterator<Item = Link<Self>> {
+        self.links.borrow().clone().into_iter()
+    }
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::Node;
+use easy_node::prelude::*;
+
+#[test]
+fn fmt() {
+    with_tree();
+    with_shared();
+    with_cycle();
+    with_weak_cycle();
+    with_nested();
+
+    fn with_tree() {
+        // Arrange.
+        let leaf = Node::new("leaf");
+        let root = Node::new("root");
+        root.link([leaf.into()]);
+        // Act.
+        let result = format!("{:?}", Nr::debug_graph(&root));
+        // Assert.
+        let expected = concat!(
+            r#"#0 Node { name: "root", links: RefCell { value: [Strong("#,
+            r#"#1 Node { name: "leaf", links: RefCell { value: [] } })] } }"#
+        );
+        assert_eq!(result, expected);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let leaf = Node::new("leaf");
+        let root = Node::new("root");
+        root.link([leaf.clone().into(), leaf.into()]);
+        // Act.
+        let result = format!("{:?}", Nr::debug_graph(&root));
+        // Assert.
+        let expected = concat!(
+            r#"#0 Node { name: "root", links: RefCell { value: [Strong("#,
+            r#"#1 Node { name: "leaf", links: RefCell { value: [] } }), Strong(#1)] } }"#
+        );
+        assert_eq!(result, expected);
+    }
+
+    fn with_cycle() {
+        // Arrange.
+        let leaf = Node::new("leaf");
+        let root = Node::new("root");
+        root.link([leaf.clone().into()]);
+        leaf.link([root.clone().into()]);
+        // Act.
+        let result = format!("{:?}", Nr::debug_graph(&root));
+        leaf.links.borrow_mut().clear();
+        // Assert.
+        let expected = concat!(
+            r#"#0 Node { name: "root", links: RefCell { value: [Strong("#,
+            r#"#1 Node { name: "leaf", links: RefCell { value: [Strong(#0)] } })] } }"#
+        );
+        assert_eq!(result, expected);
+    }
+
+    fn with_weak_cycle() {
+        // Arrange.
+        let target = Nr::new_cyclic(|me| Cyclic { me: me.clone() });
+        // Act.
+        let result = format!("{:?}", Nr::debug_graph(&target));
+        // Assert.
+        assert_eq!(result, "#0 Cyclic { me: #0 }");
+    }
+
+    fn with_nested() {
+        // Arrange.
+        let leaf = Node::new("leaf");
+        let root = Node::new("root");
+        root.link([leaf.into()]);
+        // Act.
+        let result1 = format!("{:?}", Nr::debug_graph(&root));
+        let result2 = format!("{:?}", Nr::debug_graph(&root));
+        // Assert.
+        assert_eq!(result1, result2);
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Cyclic {
+    me: Nw<Cyclic>,
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::{Node, sample_graph};
+use easy_node::Dfs;
+
+#[test]
+fn new() {
+    // Arrange.
+    let root = sample_graph();
+    // Act.
+    let result = Dfs::new(&root);
+    // Assert.
+    let names = result.map(|x| x.name).collect::<Vec<_>>();
+    assert_eq!(names, ["a", "b", "d", "c"]);
+}
+
+#[test]
+fn from_roots() {
+    // Arrange.
+    let root = sample_graph();
+    let other = Node::new("x");
+    let roots = [other.clone(), root, other.clone()];
+    // Act.
+    let result = Dfs::from_roots(roots);
+    // Assert.
+    let names = result.map(|x| x.name).collect::<Vec<_>>();
+    assert_eq!(names, ["x", "a", "b", "d", "c"]);
+}
@@ -0,0 +1,28 @@
+mod common;
+
+use common::{Node, sample_graph};
+use easy_node::DfsPostOrder;
+
+#[test]
+fn new() {
+    // Arrange.
+    let root = sample_graph();
+    // Act.
+    let result = DfsPostOrder::new(&root);
+    // Assert.
+    let names = result.map(|x| x.name).collect::<Vec<_>>();
+    assert_eq!(names, ["d", "b", "c", "a"]);
+}
+
+#[test]
+fn from_roots() {
+    // Arrange.
+    let root = sample_graph();
+    let other = Node::new("x");
+    let roots = [other.clone(), root, other.clone()];
+    // Act.
+    let result = DfsPostOrder::from_roots(roots);
+    // Assert.
+    let names = result.map(|x| x.name).collect::<Vec<_>>();
+    assert_eq!(names, ["x", "d", "b", "c", "a"]);
+}
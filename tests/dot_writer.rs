@@ -0,0 +1,89 @@
+mod common;
+
+use common::{Node, sample_graph};
+use easy_node::DotWriter;
+
+#[test]
+fn new() {
+    // Arrange.
+    let root = sample_graph();
+    let target = DotWriter::new();
+    // Act.
+    let result = target.render([root]);
+    // Assert.
+    let expected = concat!(
+        "digraph {\n",
+        "    n0;\n",
+        "    n1;\n",
+        "    n2;\n",
+        "    n3;\n",
+        "    n0 -> n1;\n",
+        "    n0 -> n2;\n",
+        "    n1 -> n3;\n",
+        "    n2 -> n3;\n",
+        "    n2 -> n0 [style=dashed];\n",
+        "}\n",
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn node_label() {
+    // Arrange.
+    let root = sample_graph();
+    let target = DotWriter::new().node_label(|x: &Node| format!("\"{}\"", x.name));
+    // Act.
+    let result = target.render([root]);
+    // Assert.
+    assert!(result.contains("    n0 [label=\"\\\"a\\\"\"];\n"));
+    assert!(result.contains("    n1 [label=\"\\\"b\\\"\"];\n"));
+    assert!(result.contains("    n3 [label=\"\\\"d\\\"\"];\n"));
+}
+
+#[test]
+fn edge_label() {
+    // Arrange.
+    let root = sample_graph();
+    let label = |x: &Node, y: &Node| format!("{}{}", x.name, y.name);
+    let target = DotWriter::new().edge_label(label);
+    // Act.
+    let result = target.render([root]);
+    // Assert.
+    assert!(result.contains("    n0 -> n1 [label=\"ab\"];\n"));
+    assert!(result.contains("    n2 -> n0 [style=dashed, label=\"ca\"];\n"));
+}
+
+#[test]
+fn write() {
+    with_normal();
+    with_link_added();
+
+    fn with_normal() {
+        // Arrange.
+        let root = sample_graph();
+        let other = Node::new("x");
+        let target = DotWriter::new();
+        let mut result = String::new();
+        // Act.
+        let roots = [root.clone(), other, root];
+        target.write(&mut result, roots).unwrap();
+        // Assert.
+        assert_eq!(result.matches(";\n").count(), 5 + 5);
+    }
+
+    fn with_link_added() {
+        // Arrange.
+        let root = sample_graph();
+        let label = |x: &Node| {
+            x.link([Node::new("y").into()]);
+            x.name.to_string()
+        };
+        let target = DotWriter::new().node_label(label);
+        let mut result = String::new();
+        // Act.
+        target.write(&mut result, [root.clone()]).unwrap();
+        // Assert.
+        assert_eq!(result.matches(";\n").count(), 4 + 5);
+        root.links.borrow_mut().pop();
+    }
+}
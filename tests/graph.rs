@@ -0,0 +1,286 @@
+use easy_node::prelude::*;
+use easy_node::{Bfs, Graph};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn new() {
+    // Act.
+    let result = Graph::<i32>::new();
+    // Assert.
+    assert_eq!(result.node_count(), 0);
+    assert_eq!(result.edge_count(), 0);
+    assert!(result.is_empty());
+}
+
+#[test]
+fn contains() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let mut other = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let node2 = other.add_node(2);
+    // Act.
+    let result1 = target.contains(&node1);
+    let result2 = target.contains(&node2);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn nodes() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    target.add_node(1);
+    let node = target.add_node(2);
+    target.add_node(3);
+    target.remove_node(&node);
+    target.add_node(4);
+    // Act.
+    let result = target.nodes();
+    // Assert.
+    let result = result.map(|x| *x.value()).collect::<Vec<_>>();
+    assert_eq!(result, [1, 3, 4]);
+}
+
+#[test]
+fn add_node() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    // Act.
+    let result = target.add_node(1);
+    // Assert.
+    assert_eq!(*result.value(), 1);
+    assert!(result.is_linked());
+    assert_eq!(target.node_count(), 1);
+}
+
+#[test]
+fn add_edge() {
+    with_new();
+    with_existing();
+    with_foreign();
+
+    fn with_new() {
+        // Arrange.
+        let mut target = Graph::new();
+        let node1 = target.add_node(1);
+        let node2 = target.add_node(2);
+        // Act.
+        let result = target.add_edge(&node1, &node2, "a");
+        // Assert.
+        assert!(result.is_none());
+        assert_eq!(target.edge_count(), 1);
+        assert_eq!(*target.edge(&node1, &node2).unwrap(), "a");
+        assert!(target.edge(&node2, &node1).is_none());
+    }
+
+    fn with_existing() {
+        // Arrange.
+        let mut target = Graph::new();
+        let node1 = target.add_node(1);
+        let node2 = target.add_node(2);
+        target.add_edge(&node1, &node2, "a");
+        // Act.
+        let result = target.add_edge(&node1, &node2, "b");
+        // Assert.
+        assert_eq!(result, Some("a"));
+        assert_eq!(target.edge_count(), 1);
+        assert_eq!(*target.edge(&node1, &node2).unwrap(), "b");
+        assert_eq!(target.in_neighbors(&node2).len(), 1);
+    }
+
+    fn with_foreign() {
+        // Arrange.
+        let mut target = Graph::<i32>::new();
+        let mut other = Graph::<i32>::new();
+        let node1 = target.add_node(1);
+        let node2 = other.add_node(2);
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            target.add_edge(&node1, &node2, ());
+        }));
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn remove_edge() {
+    with_existing();
+    with_missing();
+
+    fn with_existing() {
+        // Arrange.
+        let mut target = Graph::new();
+        let node1 = target.add_node(1);
+        let node2 = target.add_node(2);
+        target.add_edge(&node1, &node2, "a");
+        // Act.
+        let result = target.remove_edge(&node1, &node2);
+        // Assert.
+        assert_eq!(result, Some("a"));
+        assert_eq!(target.edge_count(), 0);
+        assert_eq!(target.neighbors(&node1).len(), 0);
+        assert_eq!(target.in_neighbors(&node2).len(), 0);
+    }
+
+    fn with_missing() {
+        // Arrange.
+        let mut target = Graph::new();
+        let node1 = target.add_node(1);
+        let node2 = target.add_node(2);
+        target.add_edge(&node1, &node2, "a");
+        // Act.
+        let result = target.remove_edge(&node2, &node1);
+        // Assert.
+        assert!(result.is_none());
+        assert_eq!(target.edge_count(), 1);
+    }
+}
+
+#[test]
+fn remove_node() {
+    with_edges();
+    with_self_loop();
+    with_removed();
+
+    fn with_edges() {
+        // Arrange.
+        let mut target = Graph::<i32>::new();
+        let node1 = target.add_node(1);
+        let node2 = target.add_node(2);
+        let node3 = target.add_node(3);
+        target.add_edge(&node1, &node2, ());
+        target.add_edge(&node2, &node3, ());
+        target.add_edge(&node1, &node3, ());
+        // Act.
+        let result = target.remove_node(&node2);
+        // Assert.
+        assert!(result);
+        assert!(!node2.is_linked());
+        assert_eq!(target.node_count(), 2);
+        assert_eq!(target.edge_count(), 1);
+        assert!(target.neighbors(&node1).eq([node3.clone()]));
+        assert!(target.in_neighbors(&node3).eq([node1]));
+        assert_eq!(target.neighbors(&node2).len(), 0);
+        assert_eq!(target.in_neighbors(&node2).len(), 0);
+    }
+
+    fn with_self_loop() {
+        // Arrange.
+        let mut target = Graph::<i32>::new();
+        let node = target.add_node(1);
+        target.add_edge(&node, &node, ());
+        // Act.
+        let result = target.remove_node(&node);
+        // Assert.
+        assert!(result);
+        assert!(target.is_empty());
+        assert_eq!(target.edge_count(), 0);
+    }
+
+    fn with_removed() {
+        // Arrange.
+        let mut target = Graph::<i32>::new();
+        let node = target.add_node(1);
+        target.remove_node(&node);
+        // Act.
+        let result = target.remove_node(&node);
+        // Assert.
+        assert!(!result);
+    }
+}
+
+#[test]
+fn neighbors() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let node2 = target.add_node(2);
+    let node3 = target.add_node(3);
+    target.add_edge(&node1, &node2, ());
+    target.add_edge(&node1, &node3, ());
+    target.add_edge(&node3, &node1, ());
+    // Act.
+    let result = target.neighbors(&node1);
+    // Assert.
+    assert_eq!(result.collect::<Vec<_>>(), [node2, node3]);
+}
+
+#[test]
+fn in_neighbors() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let node2 = target.add_node(2);
+    let node3 = target.add_node(3);
+    target.add_edge(&node2, &node1, ());
+    target.add_edge(&node3, &node1, ());
+    target.add_edge(&node1, &node3, ());
+    // Act.
+    let result = target.in_neighbors(&node1);
+    // Assert.
+    assert_eq!(result.collect::<Vec<_>>(), [node2, node3]);
+}
+
+#[test]
+fn clear() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let node2 = target.add_node(2);
+    target.add_edge(&node1, &node2, ());
+    // Act.
+    target.clear();
+    // Assert.
+    assert!(target.is_empty());
+    assert_eq!(target.edge_count(), 0);
+    assert!(!node1.is_linked());
+    assert_eq!(Nr::strong_count(&node1), 1);
+    assert_eq!(Nr::weak_count(&node1), 0);
+}
+
+#[test]
+fn drop() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let weak = Nr::downgrade(&target.add_node(2));
+    target.add_edge(&node1, &weak.upgrade().unwrap(), ());
+    // Act.
+    std::mem::drop(target);
+    // Assert.
+    assert!(!node1.is_linked());
+    assert!(weak.upgrade().is_none());
+    assert_eq!(node1.neighbors().count(), 0);
+}
+
+#[test]
+fn graph_node_neighbors() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    let node1 = target.add_node(1);
+    let node2 = target.add_node(2);
+    let node3 = target.add_node(3);
+    target.add_edge(&node1, &node2, ());
+    target.add_edge(&node2, &node3, ());
+    target.add_edge(&node3, &node1, ());
+    // Act.
+    let result = Bfs::new(&node1);
+    // Assert.
+    let result = result.map(|x| *x.value()).collect::<Vec<_>>();
+    assert_eq!(result, [1, 2, 3]);
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let mut target = Graph::<i32>::new();
+    target.add_node(1);
+    target.add_node(2);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, "[Nr(GraphNode(1)), Nr(GraphNode(2))]");
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::Node;
+use easy_node::LeakReport;
+use easy_node::prelude::*;
+
+#[test]
+fn new() {
+    with_tree();
+    with_weak_cycle();
+    with_self_cycle();
+    with_strong_cycle();
+    with_owned_cycle();
+    with_downstream();
+
+    fn with_tree() {
+        // Arrange.
+        let a = Node::new("a");
+        let b = Node::new("b");
+        a.link([b.into()]);
+        // Act.
+        let result = LeakReport::new([a]);
+        // Assert.
+        assert!(result.is_empty());
+        assert!(result.cycles().is_empty());
+    }
+
+    fn with_weak_cycle() {
+        // Arrange.
+        let a = Node::new("a");
+        let b = Node::new("b");
+        b.link([Nr::downgrade(&a).into()]);
+        a.link([b.into()]);
+        // Act.
+        let result = LeakReport::new([a]);
+        // Assert.
+        assert!(result.is_empty());
+    }
+
+    fn with_self_cycle() {
+        // Arrange.
+        let a = Node::new("a");
+        a.link([a.clone().into()]);
+        // Act.
+        let result = LeakReport::new([a]);
+        // Assert.
+        assert_eq!(names(result.leaked()), ["a"]);
+        assert_eq!(result.cycles().len(), 1);
+        assert_eq!(names(&result.cycles()[0]), ["a"]);
+        unlink(result);
+    }
+
+    fn with_strong_cycle() {
+        // Arrange.
+        let a = Node::new("a");
+        let b = Node::new("b");
+        let c = Node::new("c");
+        c.link([b.clone().into()]);
+        b.link([c.into()]);
+        a.link([b.into()]);
+        // Act.
+        let result = LeakReport::new([a]);
+        // Assert.
+        assert_eq!(names(result.leaked()), ["b", "c"]);
+        assert_eq!(result.cycles().len(), 1);
+        assert_eq!(names(&result.cycles()[0]), ["b", "c"]);
+        unlink(result);
+    }
+
+    fn with_owned_cycle() {
+        // Arrange.
+        let a = Node::new("a");
+        let b = Node::new("b");
+        b.link([a.clone().into()]);
+        a.link([b.into()]);
+        // Act.
+        let result = LeakReport::new([a.clone()]);
+        // Assert.
+        assert!(result.is_empty());
+        a.links.borrow_mut().clear();
+    }
+
+    fn with_downstream() {
+        // Arrange.
+        let a = Node::new("a");
+        let b = Node::new("b");
+        let c = Node::new("c");
+        b.link([c.into()]);
+        a.link([a.clone().into(), b.into()]);
+        // Act.
+        let result = LeakReport::new([a]);
+        // Assert.
+        assert_eq!(names(result.leaked()), ["a", "b", "c"]);
+        assert_eq!(result.cycles().len(), 1);
+        assert_eq!(names(&result.cycles()[0]), ["a"]);
+        unlink(result);
+    }
+}
+
+fn names(nodes: &[Nr<Node>]) -> Vec<&'static str> {
+    nodes.iter().map(|x| x.name).collect()
+}
+
+fn unlink(report: LeakReport<Node>) {
+    for node in report.leaked() {
+        node.links.borrow_mut().clear();
+    }
+}
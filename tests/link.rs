@@ -0,0 +1,81 @@
+use easy_node::prelude::*;
+
+#[test]
+fn is_strong() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target1 = Link::from(nr.clone());
+    let target2 = Link::from(Nr::downgrade(&nr));
+    // Act.
+    let result1 = target1.is_strong();
+    let result2 = target2.is_strong();
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn is_weak() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target1 = Link::from(nr.clone());
+    let target2 = Link::from(Nr::downgrade(&nr));
+    // Act.
+    let result1 = target1.is_weak();
+    let result2 = target2.is_weak();
+    // Assert.
+    assert!(!result1);
+    assert!(result2);
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target1 = Link::from(nr.clone());
+    let target2 = Link::from(Nr::downgrade(&nr));
+    // Act.
+    let result1 = target1.key();
+    let result2 = target2.key();
+    // Assert.
+    assert_eq!(result1, Nr::key(&nr));
+    assert_eq!(result2, Nr::key(&nr));
+}
+
+#[test]
+fn upgrade() {
+    with_strong();
+    with_weak();
+    with_dead();
+
+    fn with_strong() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = Link::Strong(nr.clone());
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, Some(nr));
+    }
+
+    fn with_weak() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = Link::Weak(Nr::downgrade(&nr));
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, Some(nr));
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = Link::Weak(Nr::downgrade(&nr));
+        drop(nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, None);
+    }
+}
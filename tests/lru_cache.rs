@@ -0,0 +1,225 @@
+use easy_node::LruCache;
+use easy_node::prelude::*;
+use std::cell::RefCell;
+use std::panic;
+use std::rc::Rc;
+
+#[test]
+fn new() {
+    with_normal();
+    with_zero();
+
+    fn with_normal() {
+        // Act.
+        let result = LruCache::<i32, i32>::new(2);
+        // Assert.
+        assert_eq!(result.capacity(), 2);
+        assert_eq!(result.len(), 0);
+        assert!(result.is_empty());
+    }
+
+    fn with_zero() {
+        // Act.
+        let result = panic::catch_unwind(|| LruCache::<i32, i32>::new(0));
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn on_evict() {
+    // Arrange.
+    let evicted = Rc::new(RefCell::new(Vec::new()));
+    let evicted_clone = evicted.clone();
+    let target = LruCache::new(2);
+    let mut target = target.on_evict(move |x| evicted_clone.borrow_mut().push(x));
+    let weak = Nr::downgrade(&target.insert(1, "a"));
+    target.insert(2, "b");
+    // Act.
+    target.insert(3, "c");
+    // Assert.
+    assert_eq!(evicted.borrow().len(), 1);
+    assert_eq!(*evicted.borrow()[0].value().key(), 1);
+    assert!(weak.upgrade().is_some());
+    evicted.borrow_mut().clear();
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn contains_key() {
+    // Arrange.
+    let mut target = LruCache::new(2);
+    target.insert("a", 1);
+    // Act.
+    let result1 = target.contains_key("a");
+    let result2 = target.contains_key("b");
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn get() {
+    with_existing();
+    with_missing();
+
+    fn with_existing() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        target.insert("a", 1);
+        target.insert("b", 2);
+        // Act.
+        let result = target.get("a");
+        // Assert.
+        assert_eq!(*result.unwrap().value().value(), 1);
+        assert_eq!(keys(&target), ["a", "b"]);
+    }
+
+    fn with_missing() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        target.insert("a", 1);
+        // Act.
+        let result = target.get("b");
+        // Assert.
+        assert!(result.is_none());
+    }
+}
+
+#[test]
+fn peek() {
+    // Arrange.
+    let mut target = LruCache::new(2);
+    target.insert("a", 1);
+    target.insert("b", 2);
+    // Act.
+    let result = target.peek("a");
+    // Assert.
+    assert_eq!(*result.unwrap().value().value(), 1);
+    assert_eq!(keys(&target), ["b", "a"]);
+}
+
+#[test]
+fn insert() {
+    with_new();
+    with_existing();
+    with_overflow();
+
+    fn with_new() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        target.insert("a", 1);
+        // Act.
+        let result = target.insert("b", 2);
+        // Assert.
+        assert_eq!(*result.value().key(), "b");
+        assert_eq!(*result.value().value(), 2);
+        assert_eq!(keys(&target), ["b", "a"]);
+    }
+
+    fn with_existing() {
+        // Arrange.
+        let evicted = Rc::new(RefCell::new(0));
+        let evicted_clone = evicted.clone();
+        let target = LruCache::new(2);
+        let mut target = target.on_evict(move |_| *evicted_clone.borrow_mut() += 1);
+        let weak = Nr::downgrade(&target.insert("a", 1));
+        target.insert("b", 2);
+        // Act.
+        let result = target.insert("a", 3);
+        // Assert.
+        assert_eq!(*result.value().value(), 3);
+        assert_eq!(keys(&target), ["a", "b"]);
+        assert_eq!(*evicted.borrow(), 0);
+        assert!(weak.upgrade().is_none());
+    }
+
+    fn with_overflow() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        let weak = Nr::downgrade(&target.insert("a", 1));
+        target.insert("b", 2);
+        // Act.
+        target.insert("c", 3);
+        // Assert.
+        assert_eq!(target.len(), 2);
+        assert_eq!(keys(&target), ["c", "b"]);
+        assert!(!target.contains_key("a"));
+        assert!(weak.upgrade().is_none());
+    }
+}
+
+#[test]
+fn remove() {
+    with_existing();
+    with_missing();
+
+    fn with_existing() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        target.insert("a", 1);
+        target.insert("b", 2);
+        // Act.
+        let result = target.remove("a");
+        // Assert.
+        let result = result.unwrap();
+        assert_eq!(*result.value().value(), 1);
+        assert!(!result.is_linked());
+        assert_eq!(keys(&target), ["b"]);
+    }
+
+    fn with_missing() {
+        // Arrange.
+        let mut target = LruCache::new(2);
+        target.insert("a", 1);
+        // Act.
+        let result = target.remove("b");
+        // Assert.
+        assert!(result.is_none());
+        assert_eq!(keys(&target), ["a"]);
+    }
+}
+
+#[test]
+fn clear() {
+    // Arrange.
+    let mut target = LruCache::new(2);
+    let weak = Nr::downgrade(&target.insert("a", 1));
+    // Act.
+    target.clear();
+    // Assert.
+    assert!(target.is_empty());
+    assert!(!target.contains_key("a"));
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn iter() {
+    // Arrange.
+    let mut target = LruCache::new(3);
+    target.insert("a", 1);
+    target.insert("b", 2);
+    target.insert("c", 3);
+    target.get("b");
+    // Act.
+    let result = target.iter();
+    // Assert.
+    let result = result.map(|x| *x.value().value()).collect::<Vec<_>>();
+    assert_eq!(result, [2, 3, 1]);
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let mut target = LruCache::new(2);
+    target.insert("a", 1);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    let expected = r#"LruCache { capacity: 2, entries: [Nr(ListNode(LruEntry("a", 1)))] }"#;
+    assert_eq!(result, expected);
+}
+
+fn keys(cache: &LruCache<&'static str, i32>) -> Vec<&'static str> {
+    cache.iter().map(|x| *x.value().key()).collect()
+}
@@ -0,0 +1,831 @@
+use drop_tracer::prelude::*;
+use easy_node::na_unsize;
+use easy_node::prelude::*;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+#[test]
+fn new() {
+    // Act.
+    let result = Na::new(42);
+    // Assert.
+    assert_eq!(*result, 42);
+    assert_eq!(Na::strong_count(&result), 1);
+    assert_eq!(Na::weak_count(&result), 0);
+}
+
+#[test]
+fn new_cyclic() {
+    drop_test::run(|tracer| {
+        // Act.
+        let result = Na::new_cyclic(|w| {
+            let value = tracer.trace(42);
+            let me = w.clone();
+            Cyclic { value, me }
+        });
+        // Assert.
+        assert_eq!(*result.value, 42);
+        assert_eq!(&result.me, &Na::downgrade(&result));
+        assert_eq!(Na::strong_count(&result), 1);
+        assert_eq!(Na::weak_count(&result), 1);
+        assert_eq!(NwA::strong_count(&result.me), 1);
+        assert_eq!(NwA::weak_count(&result.me), 1);
+    });
+
+    #[derive(Debug)]
+    struct Cyclic<T> {
+        value: T,
+        me: NwA<Self>,
+    }
+}
+
+#[test]
+fn new_cyclic_array() {
+    drop_test::run(|tracer| {
+        // Act.
+        let result = Na::new_cyclic_array(|[w0, w1, w2]| {
+            assert!(w0.upgrade().is_none());
+            [
+                Ring(tracer.trace(0), w1.clone()),
+                Ring(tracer.trace(1), w2.clone()),
+                Ring(tracer.trace(2), w0.clone()),
+            ]
+        });
+        // Assert.
+        let [x0, x1, x2] = &result;
+        assert_eq!((*x0.0, *x1.0, *x2.0), (0, 1, 2));
+        assert!(x0.1 == *x1 && x1.1 == *x2 && x2.1 == *x0);
+        assert!(result.iter().all(|x| Na::strong_count(x) == 1));
+        assert!(result.iter().all(|x| Na::weak_count(x) == 1));
+    });
+
+    struct Ring<T>(T, NwA<Self>);
+}
+
+#[test]
+fn new_cyclic_vec() {
+    with_empty();
+    with_nodes();
+    #[cfg(feature = "nightly")]
+    with_many();
+    with_mismatch();
+
+    fn with_empty() {
+        // Act.
+        let result = Na::<()>::new_cyclic_vec(0, |ws| {
+            assert!(ws.is_empty());
+            vec![]
+        });
+        // Assert.
+        assert!(result.is_empty());
+    }
+
+    fn with_nodes() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Na::new_cyclic_vec(3, |ws| {
+                let value = |i| Star(tracer.trace(i), ws[0].clone());
+                (0..3).map(value).collect()
+            });
+            // Assert.
+            assert_eq!(result.len(), 3);
+            assert!(result.iter().enumerate().all(|(i, x)| *x.0 == i));
+            assert!(result.iter().all(|x| x.1 == result[0]));
+            assert_eq!(Na::strong_count(&result[0]), 1);
+            assert_eq!(Na::weak_count(&result[0]), 3);
+        });
+    }
+
+    #[cfg(feature = "nightly")]
+    fn with_many() {
+        // Act.
+        let result = Na::new_cyclic_vec(200_000, |ws| {
+            let value = |i: usize| Star(i, ws[(i + 1) % ws.len()].clone());
+            (0..ws.len()).map(value).collect()
+        });
+        // Assert.
+        assert_eq!(result.len(), 200_000);
+        assert!(result.iter().enumerate().all(|(i, x)| x.0 == i));
+        assert!(result[199_999].1 == result[0]);
+    }
+
+    fn with_mismatch() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                Na::new_cyclic_vec(2, |ws| vec![Star(tracer.trace(0), ws[0].clone())])
+            }));
+            // Assert.
+            assert!(result.is_err());
+        });
+    }
+
+    struct Star<T>(T, NwA<Self>);
+}
+
+#[test]
+fn try_new_cyclic() {
+    with_ok();
+    with_err();
+    with_err_weak();
+    with_panic();
+
+    fn with_ok() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Na::try_new_cyclic(|w| {
+                let value = tracer.trace(42);
+                let me = w.clone();
+                Ok::<_, ()>(Cyclic { value, me })
+            });
+            // Assert.
+            let result = result.unwrap();
+            assert_eq!(*result.value, 42);
+            assert_eq!(result.me, result);
+        });
+    }
+
+    fn with_err() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Na::<()>::try_new_cyclic(|_| Err(tracer.trace(42)));
+            // Assert.
+            assert_eq!(*result.unwrap_err(), 42);
+        });
+    }
+
+    fn with_err_weak() {
+        // Arrange.
+        let mut weak = None;
+        // Act.
+        let result = Na::<()>::try_new_cyclic(|w| {
+            weak = Some(w.clone());
+            Err(())
+        });
+        // Assert.
+        assert!(result.is_err());
+        assert!(weak.unwrap().upgrade().is_none());
+    }
+
+    fn with_panic() {
+        // Act.
+        let result = std::panic::catch_unwind(|| {
+            let _ = Na::<()>::try_new_cyclic::<(), _>(|_| panic!("foo"));
+        });
+        // Assert.
+        assert_eq!(result.unwrap_err().downcast_ref(), Some(&"foo"));
+    }
+
+    #[derive(Debug)]
+    struct Cyclic<T> {
+        value: T,
+        me: NwA<Self>,
+    }
+}
+
+#[test]
+fn try_unwrap() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let target = Na::new(42);
+        // Act.
+        let result = Na::try_unwrap(target);
+        // Assert.
+        assert_eq!(result, Ok(42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let target = Na::new(42);
+        let other = Na::clone(&target);
+        // Act.
+        let result = Na::try_unwrap(target);
+        // Assert.
+        assert_eq!(result, Err(other));
+    }
+}
+
+#[test]
+fn into_inner() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let target = Na::new(42);
+        // Act.
+        let result = Na::into_inner(target);
+        // Assert.
+        assert_eq!(result, Some(42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let target = Na::new(42);
+        let other = Na::clone(&target);
+        // Act.
+        let result = Na::into_inner(target);
+        // Assert.
+        assert_eq!(result, None);
+        assert_eq!(Na::strong_count(&other), 1);
+    }
+}
+
+#[test]
+fn unwrap_or_clone() {
+    // Arrange.
+    let target = Na::new(42);
+    let other = Na::clone(&target);
+    // Act.
+    let result = Na::unwrap_or_clone(target);
+    // Assert.
+    assert_eq!(result, 42);
+    assert_eq!(Na::strong_count(&other), 1);
+}
+
+#[test]
+fn as_base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    // Act.
+    let result = Na::as_base(&arc);
+    // Assert.
+    assert_eq!(result, Na::as_base(&arc));
+}
+
+#[test]
+fn from_base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    // Act.
+    let result = Na::from_base(arc.clone());
+    // Assert.
+    assert_eq!(result, Na::from_base(arc.clone()));
+}
+
+#[test]
+fn into_base() {
+    // Arrange.
+    let target = Na::new(42);
+    let ptr = Na::as_ptr(&target);
+    // Act.
+    let result = Na::into_base(target);
+    // Assert.
+    assert_eq!(Arc::as_ptr(&result), ptr);
+    assert_eq!(Arc::strong_count(&result), 1);
+}
+
+#[test]
+fn as_ptr() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = Na::as_ptr(&target);
+    // Assert.
+    assert_eq!(result, Na::as_ptr(&target.clone()));
+}
+
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+    with_send_sync();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Na::new(42);
+        let target = Na::<dyn Any>::from(Arc::clone(Na::base(&nr)) as Arc<dyn Any>);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nr);
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Na::new(42);
+        let target = Na::<dyn Any>::from(Arc::clone(Na::base(&nr)) as Arc<dyn Any>);
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        let result = result.unwrap_err();
+        assert_eq!(Na::as_ptr(&result).cast(), Na::as_ptr(&nr));
+    }
+
+    fn with_send_sync() {
+        // Arrange.
+        let nr = Na::new(42);
+        let base = Arc::clone(Na::base(&nr)) as Arc<dyn Any + Send + Sync>;
+        let target = Na::from_base(base);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nr);
+    }
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = Na::key(&target);
+    // Assert.
+    assert_eq!(result.addr(), Na::as_ptr(&target).cast());
+    assert_eq!(result, Na::downgrade(&target).key());
+}
+
+#[test]
+fn ptr_eq() {
+    when_same();
+    when_diff();
+    when_unsized();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::clone(&target1);
+        // Act.
+        let result = Na::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::new(());
+        // Act.
+        let result = Na::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn when_unsized() {
+        // Arrange.
+        let node = Na::new(42);
+        let target1 = na_unsize!(Na::clone(&node), dyn Any);
+        let target2 = na_unsize!(node, dyn Debug);
+        // Act.
+        let result = Na::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_eq_weak() {
+    when_same();
+    when_diff();
+    when_unsized();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::downgrade(&target1);
+        // Act.
+        let result = Na::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::downgrade(&Na::new(()));
+        // Act.
+        let result = Na::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn when_unsized() {
+        // Arrange.
+        let node = Na::new(42);
+        let target1 = na_unsize!(Na::clone(&node), dyn Any);
+        let target2 = Na::downgrade(&node);
+        // Act.
+        let result = Na::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_cmp() {
+    // Arrange.
+    let node1 = Na::new(1);
+    let node2 = Na::new(2);
+    let target1 = na_unsize!(Na::clone(&node1), dyn Any);
+    let target2 = na_unsize!(Na::clone(&node2), dyn Debug);
+    // Act.
+    let result = Na::ptr_cmp(&target1, &target2);
+    // Assert.
+    assert_eq!(result, node1.cmp(&node2));
+}
+
+#[test]
+fn into_raw() {
+    // Arrange.
+    let target = Na::new(42);
+    let ptr = Na::as_ptr(&target);
+    // Act.
+    let result = Na::into_raw(target);
+    // Assert.
+    assert_eq!(result, ptr);
+    drop(unsafe { Na::from_raw(result) });
+}
+
+#[test]
+fn from_raw() {
+    // Arrange.
+    let nr = Na::new(42);
+    let ptr = Na::into_raw(nr.clone());
+    // Act.
+    let result = unsafe { Na::from_raw(ptr) };
+    // Assert.
+    assert_eq!(result, nr);
+    assert_eq!(Na::strong_count(&nr), 2);
+}
+
+#[test]
+fn increment_strong_count() {
+    // Arrange.
+    let nr = Na::new(42);
+    let ptr = Na::as_ptr(&nr);
+    // Act.
+    unsafe { Na::increment_strong_count(ptr) };
+    // Assert.
+    assert_eq!(Na::strong_count(&nr), 2);
+    drop(unsafe { Na::from_raw(ptr) });
+}
+
+#[test]
+fn decrement_strong_count() {
+    // Arrange.
+    let nr = Na::new(42);
+    let ptr = Na::into_raw(nr.clone());
+    // Act.
+    unsafe { Na::decrement_strong_count(ptr) };
+    // Assert.
+    assert_eq!(Na::strong_count(&nr), 1);
+}
+
+#[test]
+fn base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    let target = Na::from_base(arc.clone());
+    // Act.
+    let result = Na::base(&target);
+    // Assert.
+    assert!(Arc::ptr_eq(result, &arc));
+}
+
+#[test]
+fn get_mut() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let mut target = Na::new(42);
+        // Act.
+        let result = Na::get_mut(&mut target);
+        // Assert.
+        assert_eq!(result, Some(&mut 42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let mut target = Na::new(42);
+        let _weak = Na::downgrade(&target);
+        // Act.
+        let result = Na::get_mut(&mut target);
+        // Assert.
+        assert_eq!(result, None);
+    }
+}
+
+#[test]
+fn make_mut() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let mut target = Na::new(42);
+        let old = Na::as_ptr(&target);
+        // Act.
+        *Na::make_mut(&mut target) += 1;
+        // Assert.
+        assert_eq!(*target, 43);
+        assert_eq!(Na::as_ptr(&target), old);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let mut target = Na::new(42);
+        let other = Na::clone(&target);
+        // Act.
+        *Na::make_mut(&mut target) += 1;
+        // Assert.
+        assert_eq!(*target, 43);
+        assert_eq!(*other, 42);
+        assert_ne!(target, other);
+    }
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = Na::downgrade(&target);
+    // Assert.
+    assert_eq!(Na::strong_count(&target), 1);
+    assert_eq!(NwA::strong_count(&result), 1);
+    assert_eq!(Na::weak_count(&target), 1);
+    assert_eq!(NwA::weak_count(&result), 1);
+}
+
+#[test]
+fn clone() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = Na::clone(&target);
+    // Assert.
+    assert_eq!(Na::strong_count(&target), 2);
+    assert_eq!(Na::strong_count(&result), 2);
+    assert_eq!(&result, &target);
+}
+
+#[test]
+fn default() {
+    // Act.
+    let result = <Na<i32> as Default>::default();
+    // Assert.
+    assert_eq!(*result, i32::default());
+}
+
+#[test]
+fn debug_graph() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = format!("{:?}", Na::debug_graph(&target));
+    // Assert.
+    assert_eq!(&result, "#0 42");
+}
+
+#[test]
+fn fmt() {
+    // Arrange.
+    let target = Na::new("test");
+    // Act.
+    let result = format!("{target}");
+    // Assert.
+    assert_eq!(&result, "test")
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = Na::new("test");
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(&result, r#"Na("test")"#)
+}
+
+#[test]
+fn from() {
+    with_value();
+    with_box();
+    with_base();
+    with_vec();
+    with_slice();
+    with_string();
+    with_str();
+
+    fn with_value() {
+        // Act.
+        let result = Na::from(42);
+        // Assert.
+        assert_eq!(*result, 42);
+    }
+
+    fn with_box() {
+        // Act.
+        let result = Na::<[i32]>::from(Box::from([1, 2]));
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_base() {
+        // Arrange.
+        let base = Arc::new(42);
+        // Act.
+        let result1 = Na::<i32>::from(base.clone());
+        let result2 = Arc::from(result1.clone());
+        // Assert.
+        assert!(Arc::ptr_eq(Na::base(&result1), &base));
+        assert!(Arc::ptr_eq(&result2, &base));
+    }
+
+    fn with_vec() {
+        // Act.
+        let result = Na::<[i32]>::from(vec![1, 2]);
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_slice() {
+        // Act.
+        let result = Na::<[i32]>::from(&[1, 2][..]);
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_string() {
+        // Act.
+        let result = Na::<str>::from(String::from("test"));
+        // Assert.
+        assert_eq!(&*result, "test");
+    }
+
+    fn with_str() {
+        // Act.
+        let result = Na::<str>::from("test");
+        // Assert.
+        assert_eq!(&*result, "test");
+    }
+}
+
+#[test]
+fn from_iter() {
+    // Act.
+    let result = (1..=3).collect::<Na<[i32]>>();
+    // Assert.
+    assert_eq!(&*result, [1, 2, 3]);
+    assert_eq!(result, Na::clone(&result));
+}
+
+#[test]
+fn hash() {
+    when_same();
+    when_diff();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::clone(&target1);
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+        // Act.
+        target1.hash(&mut hasher1);
+        target2.hash(&mut hasher2);
+        // Assert.
+        assert_eq!(hasher1.finish(), hasher2.finish());
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::new(());
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+        // Act.
+        target1.hash(&mut hasher1);
+        target2.hash(&mut hasher2);
+        // Assert.
+        assert_ne!(hasher1.finish(), hasher2.finish());
+    }
+}
+
+#[test]
+fn cmp() {
+    when_same();
+    when_diff();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::clone(&target1);
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::new(());
+        // Act.
+        let result1 = target1.cmp(&target2);
+        let result2 = target2.cmp(&target1);
+        let results = [result1, result2];
+        // Assert.
+        let expecteds = [Ordering::Less, Ordering::Greater];
+        assert_eq!(HashSet::from(results), HashSet::from(expecteds));
+    }
+}
+
+#[test]
+fn eq() {
+    when_same();
+    when_diff();
+    when_weak();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::clone(&target1);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::new(());
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn when_weak() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::downgrade(&target1);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn partial_cmp() {
+    when_same();
+    when_diff();
+    when_weak();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::clone(&target1);
+        // Act.
+        let result = target1.partial_cmp(&target2);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::new(());
+        // Act.
+        let result1 = target1.partial_cmp(&target2);
+        let result2 = target2.partial_cmp(&target1);
+        let results = [result1, result2];
+        // Assert.
+        let expecteds = [Some(Ordering::Less), Some(Ordering::Greater)];
+        assert_eq!(HashSet::from(results), HashSet::from(expecteds));
+    }
+
+    fn when_weak() {
+        // Arrange.
+        let target1 = Na::new(());
+        let target2 = Na::downgrade(&target1);
+        // Act.
+        let result = target1.partial_cmp(&target2);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
+}
+
+#[test]
+fn send() {
+    // Arrange.
+    let target = Na::new(42);
+    let moved = Na::clone(&target);
+    // Act.
+    let result = std::thread::spawn(move || moved).join().unwrap();
+    // Assert.
+    assert_eq!(result, target);
+    assert_eq!(*result, 42);
+}
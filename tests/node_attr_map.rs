@@ -0,0 +1,198 @@
+use easy_node::NodeAttrMap;
+use easy_node::prelude::*;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+#[test]
+fn new() {
+    // Act.
+    let result = NodeAttrMap::<(), i32>::new();
+    // Assert.
+    assert_eq!(result.len(), 0);
+    assert!(result.is_empty());
+}
+
+#[test]
+fn len() {
+    // Arrange.
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr1, 1);
+    target.insert(&nr2, 2);
+    drop(nr1);
+    // Act.
+    let result = target.len();
+    // Assert.
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn get() {
+    with_some();
+    with_none();
+
+    fn with_some() {
+        // Arrange.
+        let nr = Nr::new(());
+        let mut target = NodeAttrMap::new();
+        target.insert(&nr, 42);
+        // Act.
+        let result = target.get(&nr);
+        // Assert.
+        assert_eq!(result, Some(&42));
+    }
+
+    fn with_none() {
+        // Arrange.
+        let nr1 = Nr::new(());
+        let nr2 = Nr::new(());
+        let mut target = NodeAttrMap::new();
+        target.insert(&nr1, 42);
+        // Act.
+        let result = target.get(&nr2);
+        // Assert.
+        assert_eq!(result, None);
+    }
+}
+
+#[test]
+fn get_mut() {
+    // Arrange.
+    let nr = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr, 1);
+    // Act.
+    *target.get_mut(&nr).unwrap() += 1;
+    // Assert.
+    assert_eq!(target.get(&nr), Some(&2));
+}
+
+#[test]
+fn entry() {
+    with_or_insert();
+    with_or_insert_with();
+    with_or_default();
+    with_and_modify();
+
+    fn with_or_insert() {
+        // Arrange.
+        let nr = Nr::new(());
+        let mut target = NodeAttrMap::new();
+        // Act.
+        *target.entry(&nr).or_insert(0) += 1;
+        *target.entry(&nr).or_insert(0) += 1;
+        // Assert.
+        assert_eq!(target.get(&nr), Some(&2));
+    }
+
+    fn with_or_insert_with() {
+        // Arrange.
+        let nr = Nr::new(());
+        let mut target = NodeAttrMap::new();
+        // Act.
+        *target.entry(&nr).or_insert_with(|| 1) += 1;
+        // Assert.
+        assert_eq!(target.get(&nr), Some(&2));
+    }
+
+    fn with_or_default() {
+        // Arrange.
+        let nr = Nr::new(());
+        let mut target = NodeAttrMap::<_, i32>::new();
+        // Act.
+        *target.entry(&nr).or_default() += 1;
+        // Assert.
+        assert_eq!(target.get(&nr), Some(&1));
+    }
+
+    fn with_and_modify() {
+        // Arrange.
+        let nr = Nr::new(());
+        let mut target = NodeAttrMap::new();
+        // Act.
+        target.entry(&nr).and_modify(|x| *x += 1).or_insert(1);
+        target.entry(&nr).and_modify(|x| *x += 1).or_insert(1);
+        // Assert.
+        assert_eq!(target.get(&nr), Some(&2));
+    }
+}
+
+#[test]
+fn insert() {
+    // Arrange.
+    let nr = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    // Act.
+    let result1 = target.insert(&nr, 1);
+    let result2 = target.insert(&nr, 2);
+    // Assert.
+    assert_eq!(result1, None);
+    assert_eq!(result2, Some(1));
+    assert_eq!(Nr::strong_count(&nr), 1);
+}
+
+#[test]
+fn remove() {
+    // Arrange.
+    let nr = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr, 42);
+    // Act.
+    let result1 = target.remove(&nr);
+    let result2 = target.remove(&nr);
+    // Assert.
+    assert_eq!(result1, Some(42));
+    assert_eq!(result2, None);
+}
+
+#[test]
+fn remove_dead() {
+    // Arrange.
+    let value = Rc::new(42);
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr1, value.clone());
+    target.insert(&nr2, value.clone());
+    drop(nr1);
+    // Act.
+    target.remove(&nr2);
+    // Assert.
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn purge() {
+    // Arrange.
+    let value = Rc::new(42);
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr1, value.clone());
+    target.insert(&nr2, value.clone());
+    drop(nr1);
+    // Act.
+    let result = target.purge();
+    // Assert.
+    assert_eq!(result, 1);
+    assert_eq!(Rc::strong_count(&value), 2);
+}
+
+#[test]
+fn iter() {
+    // Arrange.
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    let nr3 = Nr::new(());
+    let mut target = NodeAttrMap::new();
+    target.insert(&nr1, 1);
+    target.insert(&nr2, 2);
+    target.insert(&nr3, 3);
+    drop(nr2);
+    // Act.
+    let result = target.iter().map(|(k, v)| (k, *v)).collect::<HashSet<_>>();
+    // Assert.
+    assert_eq!(result, HashSet::from([(nr1, 1), (nr3, 3)]));
+    assert_eq!(target.purge(), 0);
+}
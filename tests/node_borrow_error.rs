@@ -0,0 +1,29 @@
+use easy_node::NodeCell;
+
+#[test]
+fn fmt_borrow_error() {
+    // Arrange.
+    let cell = NodeCell::new(42);
+    let _guard = cell.borrow_mut();
+    let target = cell.try_borrow().unwrap_err();
+    // Act.
+    let result = target.to_string();
+    // Assert.
+    let msg = format!("node {:p} is already mutably borrowed", cell.key().addr());
+    assert!(result.starts_with(&msg));
+    assert_eq!(result.contains(" (borrowed at "), cfg!(debug_assertions));
+}
+
+#[test]
+fn fmt_borrow_mut_error() {
+    // Arrange.
+    let cell = NodeCell::new(42);
+    let _guard = cell.borrow();
+    let target = cell.try_borrow_mut().unwrap_err();
+    // Act.
+    let result = target.to_string();
+    // Assert.
+    let msg = format!("node {:p} is already borrowed", cell.key().addr());
+    assert!(result.starts_with(&msg));
+    assert_eq!(result.contains(" (borrowed at "), cfg!(debug_assertions));
+}
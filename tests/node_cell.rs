@@ -0,0 +1,250 @@
+use easy_node::NodeCell;
+use easy_node::prelude::*;
+use std::panic::{AssertUnwindSafe, Location, catch_unwind};
+
+#[test]
+fn new() {
+    // Act.
+    let result = NodeCell::new(42);
+    // Assert.
+    assert_eq!(*result.borrow(), 42);
+}
+
+#[test]
+fn into_inner() {
+    // Arrange.
+    let target = NodeCell::new(42);
+    // Act.
+    let result = target.into_inner();
+    // Assert.
+    assert_eq!(result, 42);
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let node = Nr::new(NodeCell::new(42));
+    // Act.
+    let result = node.key();
+    // Assert.
+    assert_eq!(result, Nr::key(&node));
+}
+
+#[test]
+fn borrow() {
+    with_shared();
+    with_mut();
+
+    fn with_shared() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        let _guard = target.borrow();
+        // Act.
+        let result = target.borrow();
+        // Assert.
+        assert_eq!(*result, 42);
+    }
+
+    fn with_mut() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        let _guard = target.borrow_mut();
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _ = target.borrow();
+        }));
+        // Assert.
+        let msg = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(msg.starts_with("node 0x"));
+        assert!(msg.contains("already mutably borrowed"));
+    }
+}
+
+#[test]
+fn try_borrow() {
+    with_free();
+    with_mut();
+    with_released();
+
+    fn with_free() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        // Act.
+        let result = target.try_borrow();
+        // Assert.
+        assert_eq!(*result.unwrap(), 42);
+    }
+
+    fn with_mut() {
+        // Arrange.
+        let node = Nr::new(NodeCell::new(42));
+        let guard = node.borrow_mut();
+        let line = line!() - 1;
+        // Act.
+        let result = node.try_borrow();
+        // Assert.
+        let err = result.unwrap_err();
+        assert_eq!(err.addr(), Nr::key(&node).addr());
+        assert_location(err.location(), line);
+        drop(guard);
+    }
+
+    fn with_released() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        drop(target.borrow_mut());
+        // Act.
+        let result = target.try_borrow();
+        // Assert.
+        assert!(result.is_ok());
+    }
+}
+
+#[test]
+fn borrow_mut() {
+    with_free();
+    with_shared();
+
+    fn with_free() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        // Act.
+        *target.borrow_mut() += 1;
+        // Assert.
+        assert_eq!(*target.borrow(), 43);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        let _guard = target.borrow();
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _ = target.borrow_mut();
+        }));
+        // Assert.
+        let msg = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(msg.contains("already borrowed"));
+    }
+}
+
+#[test]
+fn try_borrow_mut() {
+    with_free();
+    with_shared();
+    with_shared_released();
+    with_mut();
+
+    fn with_free() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        // Act.
+        let result = target.try_borrow_mut();
+        // Assert.
+        assert_eq!(*result.unwrap(), 42);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let node = Nr::new(NodeCell::new(42));
+        let guard1 = node.borrow();
+        let line = line!() - 1;
+        let guard2 = node.borrow();
+        // Act.
+        let result = node.try_borrow_mut();
+        // Assert.
+        let err = result.unwrap_err();
+        assert_eq!(err.addr(), Nr::key(&node).addr());
+        assert_location(err.location(), line);
+        drop((guard1, guard2));
+    }
+
+    fn with_shared_released() {
+        // Arrange.
+        let node = Nr::new(NodeCell::new(42));
+        let guard1 = node.borrow();
+        let guard2 = node.borrow();
+        let line = line!() - 1;
+        drop(guard1);
+        // Act.
+        let result = node.try_borrow_mut();
+        // Assert.
+        assert_location(result.unwrap_err().location(), line);
+        drop(guard2);
+    }
+
+    fn with_mut() {
+        // Arrange.
+        let node = Nr::new(NodeCell::new(42));
+        let guard = node.borrow_mut();
+        let line = line!() - 1;
+        // Act.
+        let result = node.try_borrow_mut();
+        // Assert.
+        let err = result.unwrap_err();
+        assert_eq!(err.addr(), Nr::key(&node).addr());
+        assert_location(err.location(), line);
+        drop(guard);
+    }
+}
+
+#[test]
+fn get_mut() {
+    // Arrange.
+    let mut target = NodeCell::new(42);
+    // Act.
+    *target.get_mut() += 1;
+    // Assert.
+    assert_eq!(target.into_inner(), 43);
+}
+
+#[test]
+fn default() {
+    // Act.
+    let result = NodeCell::<i32>::default();
+    // Assert.
+    assert_eq!(*result.borrow(), 0);
+}
+
+#[test]
+fn fmt_debug() {
+    with_free();
+    with_mut();
+
+    fn with_free() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        // Act.
+        let result = format!("{target:?}");
+        // Assert.
+        assert_eq!(result, "NodeCell(42)");
+    }
+
+    fn with_mut() {
+        // Arrange.
+        let target = NodeCell::new(42);
+        let _guard = target.borrow_mut();
+        // Act.
+        let result = format!("{target:?}");
+        // Assert.
+        assert_eq!(result, "NodeCell(<borrowed>)");
+    }
+}
+
+#[test]
+fn from() {
+    // Act.
+    let result = NodeCell::from(42);
+    // Assert.
+    assert_eq!(result.into_inner(), 42);
+}
+
+fn assert_location(location: Option<&Location<'_>>, line: u32) {
+    if cfg!(debug_assertions) {
+        let location = location.unwrap();
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
+    } else {
+        assert!(location.is_none());
+    }
+}
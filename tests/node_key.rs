@@ -0,0 +1,97 @@
+use easy_node::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn from_ptr() {
+    with_normal();
+    with_null();
+
+    fn with_normal() {
+        // Arrange.
+        let nr = Nr::new(42);
+        // Act.
+        let result = NodeKey::from_ptr(Nr::as_ptr(&nr));
+        // Assert.
+        assert_eq!(result, Nr::key(&nr));
+    }
+
+    fn with_null() {
+        // Act.
+        let result = std::panic::catch_unwind(|| {
+            let _ = NodeKey::from_ptr(std::ptr::null::<i32>());
+        });
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn addr() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = Nr::key(&nr);
+    // Act.
+    let result = target.addr();
+    // Assert.
+    assert_eq!(result, Nr::as_ptr(&nr).cast());
+}
+
+#[test]
+fn borrow() {
+    with_nr_set();
+    with_nw_map();
+
+    fn with_nr_set() {
+        // Arrange.
+        let nr1 = Nr::new(1);
+        let nr2 = Nr::new(2);
+        let nw1 = Nr::downgrade(&nr1);
+        let nw2 = Nr::downgrade(&nr2);
+        let target = HashSet::from([nr1.clone()]);
+        // Act.
+        let result1 = target.contains(nw1.key());
+        let result2 = target.contains(nw2.key());
+        // Assert.
+        assert!(result1);
+        assert!(!result2);
+        assert_eq!(Nr::strong_count(&nr1), 2);
+    }
+
+    fn with_nw_map() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let ptr = Nr::as_ptr(&nr);
+        let target = HashMap::from([(Nr::downgrade(&nr), "value")]);
+        // Act.
+        let result1 = target.get(Nr::key(&nr));
+        let result2 = target.get(NodeKey::from_ptr(ptr));
+        // Assert.
+        assert_eq!(result1, Some(&"value"));
+        assert_eq!(result2, Some(&"value"));
+    }
+}
+
+#[test]
+fn eq() {
+    // Arrange.
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    let nr3 = Nr::clone(&nr1);
+    // Act.
+    let result1 = Nr::key(&nr1).eq(Nr::key(&nr2));
+    let result2 = Nr::key(&nr1).eq(Nr::key(&nr3));
+    // Assert.
+    assert!(!result1);
+    assert!(result2);
+}
+
+#[test]
+fn cmp() {
+    // Arrange.
+    let nr1 = Nr::new(());
+    let nr2 = Nr::new(());
+    // Act.
+    let result = Nr::key(&nr1).cmp(Nr::key(&nr2));
+    // Assert.
+    assert_eq!(result, nr1.cmp(&nr2));
+}
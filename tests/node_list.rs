@@ -0,0 +1,367 @@
+use easy_node::prelude::*;
+use easy_node::{ListNode, NodeList};
+use std::collections::HashSet;
+use std::panic::AssertUnwindSafe;
+
+#[test]
+fn new() {
+    // Act.
+    let result = NodeList::<i32>::new();
+    // Assert.
+    assert_eq!(result.len(), 0);
+    assert!(result.is_empty());
+    assert!(result.front().is_none());
+    assert!(result.back().is_none());
+}
+
+#[test]
+fn front() {
+    // Arrange.
+    let target = NodeList::from_iter([1, 2, 3]);
+    // Act.
+    let result = target.front();
+    // Assert.
+    assert_eq!(*result.unwrap().value(), 1);
+}
+
+#[test]
+fn back() {
+    // Arrange.
+    let target = NodeList::from_iter([1, 2, 3]);
+    // Act.
+    let result = target.back();
+    // Assert.
+    assert_eq!(*result.unwrap().value(), 3);
+}
+
+#[test]
+fn contains() {
+    // Arrange.
+    let mut target = NodeList::new();
+    let mut other = NodeList::new();
+    let node1 = target.push_back(1);
+    let node2 = other.push_back(2);
+    // Act.
+    let result1 = target.contains(&node1);
+    let result2 = target.contains(&node2);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn push_front() {
+    // Arrange.
+    let mut target = NodeList::from_iter([1, 2]);
+    // Act.
+    let result = target.push_front(0);
+    // Assert.
+    assert_eq!(values(&target), [0, 1, 2]);
+    assert_eq!(target.front(), Some(result));
+}
+
+#[test]
+fn push_back() {
+    // Arrange.
+    let mut target = NodeList::from_iter([1, 2]);
+    // Act.
+    let result = target.push_back(3);
+    // Assert.
+    assert_eq!(values(&target), [1, 2, 3]);
+    assert_eq!(target.back(), Some(result));
+}
+
+#[test]
+fn pop_front() {
+    // Arrange.
+    let mut target = NodeList::from_iter([1, 2]);
+    // Act.
+    let result = target.pop_front();
+    // Assert.
+    let result = result.unwrap();
+    assert_eq!(*result.value(), 1);
+    assert!(!result.is_linked());
+    assert!(result.next().is_none());
+    assert_eq!(values(&target), [2]);
+}
+
+#[test]
+fn pop_back() {
+    // Arrange.
+    let mut target = NodeList::from_iter([1, 2]);
+    // Act.
+    let result = target.pop_back();
+    // Assert.
+    let result = result.unwrap();
+    assert_eq!(*result.value(), 2);
+    assert!(!result.is_linked());
+    assert!(result.prev().is_none());
+    assert_eq!(values(&target), [1]);
+}
+
+#[test]
+fn insert_after() {
+    with_middle();
+    with_back();
+    with_foreign();
+
+    fn with_middle() {
+        // Arrange.
+        let mut target = NodeList::new();
+        let node = target.push_back(1);
+        target.push_back(3);
+        // Act.
+        let result = target.insert_after(&node, 2);
+        // Assert.
+        assert_eq!(values(&target), [1, 2, 3]);
+        assert_eq!(result.prev(), Some(node));
+    }
+
+    fn with_back() {
+        // Arrange.
+        let mut target = NodeList::new();
+        let node = target.push_back(1);
+        // Act.
+        let result = target.insert_after(&node, 2);
+        // Assert.
+        assert_eq!(values(&target), [1, 2]);
+        assert_eq!(target.back(), Some(result));
+    }
+
+    fn with_foreign() {
+        // Arrange.
+        let mut target = NodeList::new();
+        let mut other = NodeList::new();
+        let node = other.push_back(1);
+        // Act.
+        let result = std::panic::catch_unwind(AssertUnwindSafe(move || {
+            target.insert_after(&node, 2);
+        }));
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn remove() {
+    with_front();
+    with_middle();
+    with_back();
+    with_removed();
+
+    fn with_front() {
+        // Arrange.
+        let mut target = NodeList::new();
+        let node = target.push_back(1);
+        target.extend([2, 3]);
+        // Act.
+        let result = target.remove(&node);
+        // Assert.
+        assert!(result);
+        assert_eq!(values(&target), [2, 3]);
+        assert!(target.front().unwrap().prev().is_none());
+    }
+
+    fn with_middle() {
+        // Arrange.
+        let mut target = NodeList::from_iter([1]);
+        let node = target.push_back(2);
+        target.push_back(3);
+        // Act.
+        let result = target.remove(&node);
+        // Assert.
+        assert!(result);
+        assert_eq!(values(&target), [1, 3]);
+        assert_eq!(target.len(), 2);
+        assert!(!node.is_linked());
+    }
+
+    fn with_back() {
+        // Arrange.
+        let mut target = NodeList::from_iter([1, 2]);
+        let node = target.push_back(3);
+        // Act.
+        let result = target.remove(&node);
+        // Assert.
+        assert!(result);
+        assert_eq!(values(&target), [1, 2]);
+        assert_eq!(*target.back().unwrap().value(), 2);
+    }
+
+    fn with_removed() {
+        // Arrange.
+        let mut target = NodeList::from_iter([1]);
+        let node = target.push_back(2);
+        target.remove(&node);
+        // Act.
+        let result = target.remove(&node);
+        // Assert.
+        assert!(!result);
+        assert_eq!(values(&target), [1]);
+    }
+}
+
+#[test]
+fn move_to_front() {
+    with_back();
+    with_front();
+    with_removed();
+
+    fn with_back() {
+        // Arrange.
+        let mut target = NodeList::from_iter([1, 2]);
+        let node = target.push_back(3);
+        // Act.
+        let result = target.move_to_front(&node);
+        // Assert.
+        assert!(result);
+        assert_eq!(values(&target), [3, 1, 2]);
+        assert_eq!(values_rev(&target), [2, 1, 3]);
+    }
+
+    fn with_front() {
+        // Arrange.
+        let mut target = NodeList::new();
+        let node = target.push_back(1);
+        target.push_back(2);
+        // Act.
+        let result = target.move_to_front(&node);
+        // Assert.
+        assert!(result);
+        assert_eq!(values(&target), [1, 2]);
+        assert_eq!(values_rev(&target), [2, 1]);
+    }
+
+    fn with_removed() {
+        // Arrange.
+        let mut target = NodeList::from_iter([1]);
+        let node = target.push_back(2);
+        target.remove(&node);
+        // Act.
+        let result = target.move_to_front(&node);
+        // Assert.
+        assert!(!result);
+        assert_eq!(values(&target), [1]);
+    }
+}
+
+#[test]
+fn clear() {
+    // Arrange.
+    let mut target = NodeList::new();
+    let node1 = target.push_back(1);
+    let node2 = target.push_back(2);
+    // Act.
+    target.clear();
+    // Assert.
+    assert!(target.is_empty());
+    assert!(target.front().is_none());
+    assert!(target.back().is_none());
+    assert!(!node1.is_linked() && node1.next().is_none());
+    assert!(!node2.is_linked() && node2.prev().is_none());
+}
+
+#[test]
+fn iter() {
+    with_forward();
+    with_backward();
+    with_both();
+
+    fn with_forward() {
+        // Arrange.
+        let target = NodeList::from_iter([1, 2, 3]);
+        // Act.
+        let result = target.iter();
+        // Assert.
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.map(|x| *x.value()).collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    fn with_backward() {
+        // Arrange.
+        let target = NodeList::from_iter([1, 2, 3]);
+        // Act.
+        let result = target.iter().rev();
+        // Assert.
+        assert_eq!(result.map(|x| *x.value()).collect::<Vec<_>>(), [3, 2, 1]);
+    }
+
+    fn with_both() {
+        // Arrange.
+        let target = NodeList::from_iter([1, 2, 3]);
+        let mut iter = target.iter();
+        // Act.
+        let result1 = iter.next();
+        let result2 = iter.next_back();
+        let result3 = iter.next();
+        let result4 = iter.next_back();
+        // Assert.
+        assert_eq!(*result1.unwrap().value(), 1);
+        assert_eq!(*result2.unwrap().value(), 3);
+        assert_eq!(*result3.unwrap().value(), 2);
+        assert!(result4.is_none());
+    }
+}
+
+#[test]
+#[allow(clippy::mutable_key_type)]
+fn handle() {
+    // Arrange.
+    let mut target = NodeList::new();
+    let node1 = target.push_back(1);
+    let node2 = target.push_back(1);
+    // Act.
+    let result = HashSet::from([node1.clone(), node2.clone(), node1.clone()]);
+    // Assert.
+    assert_eq!(result.len(), 2);
+    assert!(result.contains(&node1));
+    assert!(result.contains(&node2));
+}
+
+#[test]
+fn drop() {
+    // Arrange.
+    let target = NodeList::from_iter([1, 2, 3]);
+    let nodes = target.iter().collect::<Vec<_>>();
+    let weak = nodes.iter().map(Nr::downgrade).collect::<Vec<_>>();
+    // Act.
+    std::mem::drop(target);
+    // Assert.
+    assert!(nodes.iter().all(|x| !x.is_linked()));
+    assert!(nodes.iter().all(|x| Nr::strong_count(x) == 1));
+    std::mem::drop(nodes);
+    assert!(weak.iter().all(|x| x.upgrade().is_none()));
+}
+
+#[test]
+fn drop_long() {
+    // Arrange.
+    let target = NodeList::from_iter(0..1_000_000);
+    // Act.
+    std::mem::drop(target);
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = NodeList::from_iter([1, 2]);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, "[Nr(ListNode(1)), Nr(ListNode(2))]");
+}
+
+fn values(list: &NodeList<i32>) -> Vec<i32> {
+    list.iter().map(|x| *x.value()).collect()
+}
+
+fn values_rev(list: &NodeList<i32>) -> Vec<i32> {
+    let mut ret = Vec::new();
+    let mut next = list.back();
+    while let Some(node) = next {
+        ret.push(*node.value());
+        next = ListNode::prev(&node);
+    }
+
+    ret
+}
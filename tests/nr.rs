@@ -1,9 +1,13 @@
 use drop_tracer::prelude::*;
 use easy_node::prelude::*;
+use easy_node::{PinNr, nr_unsize};
+use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
+use std::marker::PhantomPinned;
 use std::rc::Rc;
 
 #[test]
@@ -41,6 +45,233 @@ fn new_cyclic() {
     }
 }
 
+#[test]
+fn new_cyclic_array() {
+    drop_test::run(|tracer| {
+        // Act.
+        let result = Nr::new_cyclic_array(|[w0, w1, w2]| {
+            assert!(w0.upgrade().is_none());
+            [
+                Ring(tracer.trace(0), w1.clone()),
+                Ring(tracer.trace(1), w2.clone()),
+                Ring(tracer.trace(2), w0.clone()),
+            ]
+        });
+        // Assert.
+        let [x0, x1, x2] = &result;
+        assert_eq!((*x0.0, *x1.0, *x2.0), (0, 1, 2));
+        assert!(x0.1 == *x1 && x1.1 == *x2 && x2.1 == *x0);
+        assert!(result.iter().all(|x| Nr::strong_count(x) == 1));
+        assert!(result.iter().all(|x| Nr::weak_count(x) == 1));
+    });
+
+    struct Ring<T>(T, Nw<Self>);
+}
+
+#[test]
+fn new_cyclic_vec() {
+    with_empty();
+    with_nodes();
+    #[cfg(feature = "nightly")]
+    with_many();
+    with_mismatch();
+
+    fn with_empty() {
+        // Act.
+        let result = Nr::<()>::new_cyclic_vec(0, |ws| {
+            assert!(ws.is_empty());
+            vec![]
+        });
+        // Assert.
+        assert!(result.is_empty());
+    }
+
+    fn with_nodes() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Nr::new_cyclic_vec(3, |ws| {
+                let value = |i| Star(tracer.trace(i), ws[0].clone());
+                (0..3).map(value).collect()
+            });
+            // Assert.
+            assert_eq!(result.len(), 3);
+            assert!(result.iter().enumerate().all(|(i, x)| *x.0 == i));
+            assert!(result.iter().all(|x| x.1 == result[0]));
+            assert_eq!(Nr::strong_count(&result[0]), 1);
+            assert_eq!(Nr::weak_count(&result[0]), 3);
+        });
+    }
+
+    #[cfg(feature = "nightly")]
+    fn with_many() {
+        // Act.
+        let result = Nr::new_cyclic_vec(200_000, |ws| {
+            let value = |i: usize| Star(i, ws[(i + 1) % ws.len()].clone());
+            (0..ws.len()).map(value).collect()
+        });
+        // Assert.
+        assert_eq!(result.len(), 200_000);
+        assert!(result.iter().enumerate().all(|(i, x)| x.0 == i));
+        assert!(result[199_999].1 == result[0]);
+    }
+
+    fn with_mismatch() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                Nr::new_cyclic_vec(2, |ws| vec![Star(tracer.trace(0), ws[0].clone())])
+            }));
+            // Assert.
+            assert!(result.is_err());
+        });
+    }
+
+    struct Star<T>(T, Nw<Self>);
+}
+
+#[test]
+fn try_new_cyclic() {
+    with_ok();
+    with_err();
+    with_err_weak();
+    with_panic();
+
+    fn with_ok() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Nr::try_new_cyclic(|w| {
+                let value = tracer.trace(42);
+                let me = w.clone();
+                Ok::<_, ()>(Cyclic { value, me })
+            });
+            // Assert.
+            let result = result.unwrap();
+            assert_eq!(*result.value, 42);
+            assert_eq!(result.me, result);
+        });
+    }
+
+    fn with_err() {
+        drop_test::run(|tracer| {
+            // Act.
+            let result = Nr::<()>::try_new_cyclic(|_| Err(tracer.trace(42)));
+            // Assert.
+            assert_eq!(*result.unwrap_err(), 42);
+        });
+    }
+
+    fn with_err_weak() {
+        // Arrange.
+        let mut weak = None;
+        // Act.
+        let result = Nr::<()>::try_new_cyclic(|w| {
+            weak = Some(w.clone());
+            Err(())
+        });
+        // Assert.
+        assert!(result.is_err());
+        assert!(weak.unwrap().upgrade().is_none());
+    }
+
+    fn with_panic() {
+        // Act.
+        let result = std::panic::catch_unwind(|| {
+            let _ = Nr::<()>::try_new_cyclic::<(), _>(|_| panic!("foo"));
+        });
+        // Assert.
+        assert_eq!(result.unwrap_err().downcast_ref(), Some(&"foo"));
+    }
+
+    #[derive(Debug)]
+    struct Cyclic<T> {
+        value: T,
+        me: Nw<Self>,
+    }
+}
+
+#[test]
+fn pin() {
+    // Act.
+    let result = Nr::pin((42, PhantomPinned));
+    // Assert.
+    assert_eq!(result.0, 42);
+    assert_eq!(PinNr::strong_count(&result), 1);
+}
+
+#[test]
+fn try_unwrap() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let target = Nr::new(42);
+        // Act.
+        let result = Nr::try_unwrap(target);
+        // Assert.
+        assert_eq!(result, Ok(42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let target = Nr::new(42);
+        let other = Nr::clone(&target);
+        // Act.
+        let result = Nr::try_unwrap(target);
+        // Assert.
+        assert_eq!(result, Err(other));
+    }
+}
+
+#[test]
+fn into_inner() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let target = Nr::new(42);
+        // Act.
+        let result = Nr::into_inner(target);
+        // Assert.
+        assert_eq!(result, Some(42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let target = Nr::new(42);
+        let other = Nr::clone(&target);
+        // Act.
+        let result = Nr::into_inner(target);
+        // Assert.
+        assert_eq!(result, None);
+        assert_eq!(Nr::strong_count(&other), 1);
+    }
+}
+
+#[test]
+fn unwrap_or_clone() {
+    // Arrange.
+    let target = Nr::new(42);
+    let other = Nr::clone(&target);
+    // Act.
+    let result = Nr::unwrap_or_clone(target);
+    // Assert.
+    assert_eq!(result, 42);
+    assert_eq!(Nr::strong_count(&other), 1);
+}
+
+#[test]
+fn into_pin() {
+    // Arrange.
+    let target = Nr::new(42);
+    let ptr = Nr::as_ptr(&target);
+    // Act.
+    let result = Nr::into_pin(target);
+    // Assert.
+    assert_eq!(PinNr::as_ptr(&result), ptr);
+}
+
 #[test]
 fn as_base() {
     // Arrange.
@@ -61,6 +292,18 @@ fn from_base() {
     assert_eq!(result, Nr::from_base(rc.clone()));
 }
 
+#[test]
+fn into_base() {
+    // Arrange.
+    let target = Nr::new(42);
+    let ptr = Nr::as_ptr(&target);
+    // Act.
+    let result = Nr::into_base(target);
+    // Assert.
+    assert_eq!(Rc::as_ptr(&result), ptr);
+    assert_eq!(Rc::strong_count(&result), 1);
+}
+
 #[test]
 fn as_ptr() {
     // Arrange.
@@ -71,6 +314,192 @@ fn as_ptr() {
     assert_eq!(result, Nr::as_ptr(&target.clone()));
 }
 
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+    with_send_sync();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = Nr::<dyn Any>::from(Rc::clone(Nr::base(&nr)) as Rc<dyn Any>);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nr);
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = Nr::<dyn Any>::from(Rc::clone(Nr::base(&nr)) as Rc<dyn Any>);
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        let result = result.unwrap_err();
+        assert_eq!(Nr::as_ptr(&result).cast(), Nr::as_ptr(&nr));
+    }
+
+    fn with_send_sync() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let base = Rc::clone(Nr::base(&nr)) as Rc<dyn Any + Send + Sync>;
+        let target = Nr::from_base(base);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap(), nr);
+    }
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let target = Nr::new(42);
+    // Act.
+    let result = Nr::key(&target);
+    // Assert.
+    assert_eq!(result.addr(), Nr::as_ptr(&target).cast());
+    assert_eq!(result, Nr::downgrade(&target).key());
+}
+
+#[test]
+fn ptr_eq() {
+    when_same();
+    when_diff();
+    when_unsized();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::clone(&target1);
+        // Act.
+        let result = Nr::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::new(());
+        // Act.
+        let result = Nr::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn when_unsized() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target1 = nr_unsize!(Nr::clone(&node), dyn Any);
+        let target2 = nr_unsize!(node, dyn Debug);
+        // Act.
+        let result = Nr::ptr_eq(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_eq_weak() {
+    when_same();
+    when_diff();
+    when_unsized();
+
+    fn when_same() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::downgrade(&target1);
+        // Act.
+        let result = Nr::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn when_diff() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::downgrade(&Nr::new(()));
+        // Act.
+        let result = Nr::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn when_unsized() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target1 = nr_unsize!(Nr::clone(&node), dyn Any);
+        let target2 = Nr::downgrade(&node);
+        // Act.
+        let result = Nr::ptr_eq_weak(&target1, &target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_cmp() {
+    // Arrange.
+    let node1 = Nr::new(1);
+    let node2 = Nr::new(2);
+    let target1 = nr_unsize!(Nr::clone(&node1), dyn Any);
+    let target2 = nr_unsize!(Nr::clone(&node2), dyn Debug);
+    // Act.
+    let result = Nr::ptr_cmp(&target1, &target2);
+    // Assert.
+    assert_eq!(result, node1.cmp(&node2));
+}
+
+#[test]
+fn into_raw() {
+    // Arrange.
+    let target = Nr::new(42);
+    let ptr = Nr::as_ptr(&target);
+    // Act.
+    let result = Nr::into_raw(target);
+    // Assert.
+    assert_eq!(result, ptr);
+    drop(unsafe { Nr::from_raw(result) });
+}
+
+#[test]
+fn from_raw() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let ptr = Nr::into_raw(nr.clone());
+    // Act.
+    let result = unsafe { Nr::from_raw(ptr) };
+    // Assert.
+    assert_eq!(result, nr);
+    assert_eq!(Nr::strong_count(&nr), 2);
+}
+
+#[test]
+fn increment_strong_count() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let ptr = Nr::as_ptr(&nr);
+    // Act.
+    unsafe { Nr::increment_strong_count(ptr) };
+    // Assert.
+    assert_eq!(Nr::strong_count(&nr), 2);
+    drop(unsafe { Nr::from_raw(ptr) });
+}
+
+#[test]
+fn decrement_strong_count() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let ptr = Nr::into_raw(nr.clone());
+    // Act.
+    unsafe { Nr::decrement_strong_count(ptr) };
+    // Assert.
+    assert_eq!(Nr::strong_count(&nr), 1);
+}
+
 #[test]
 fn base() {
     // Arrange.
@@ -82,6 +511,60 @@ fn base() {
     assert!(Rc::ptr_eq(result, &rc));
 }
 
+#[test]
+fn get_mut() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let mut target = Nr::new(42);
+        // Act.
+        let result = Nr::get_mut(&mut target);
+        // Assert.
+        assert_eq!(result, Some(&mut 42));
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let mut target = Nr::new(42);
+        let _weak = Nr::downgrade(&target);
+        // Act.
+        let result = Nr::get_mut(&mut target);
+        // Assert.
+        assert_eq!(result, None);
+    }
+}
+
+#[test]
+fn make_mut() {
+    with_unique();
+    with_shared();
+
+    fn with_unique() {
+        // Arrange.
+        let mut target = Nr::new(42);
+        let old = Nr::as_ptr(&target);
+        // Act.
+        *Nr::make_mut(&mut target) += 1;
+        // Assert.
+        assert_eq!(*target, 43);
+        assert_eq!(Nr::as_ptr(&target), old);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let mut target = Nr::new(42);
+        let other = Nr::clone(&target);
+        // Act.
+        *Nr::make_mut(&mut target) += 1;
+        // Assert.
+        assert_eq!(*target, 43);
+        assert_eq!(*other, 42);
+        assert_ne!(target, other);
+    }
+}
+
 #[test]
 fn downgrade() {
     // Arrange.
@@ -115,6 +598,16 @@ fn default() {
     assert_eq!(*result, i32::default());
 }
 
+#[test]
+fn debug_graph() {
+    // Arrange.
+    let target = Nr::new(42);
+    // Act.
+    let result = format!("{:?}", Nr::debug_graph(&target));
+    // Assert.
+    assert_eq!(&result, "#0 42");
+}
+
 #[test]
 fn fmt() {
     // Arrange.
@@ -125,6 +618,89 @@ fn fmt() {
     assert_eq!(&result, "test")
 }
 
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = Nr::new("test");
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(&result, r#"Nr("test")"#)
+}
+
+#[test]
+fn from() {
+    with_value();
+    with_box();
+    with_base();
+    with_vec();
+    with_slice();
+    with_string();
+    with_str();
+
+    fn with_value() {
+        // Act.
+        let result = Nr::from(42);
+        // Assert.
+        assert_eq!(*result, 42);
+    }
+
+    fn with_box() {
+        // Act.
+        let result = Nr::<[i32]>::from(Box::from([1, 2]));
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_base() {
+        // Arrange.
+        let base = Rc::new(42);
+        // Act.
+        let result1 = Nr::<i32>::from(base.clone());
+        let result2 = Rc::from(result1.clone());
+        // Assert.
+        assert!(Rc::ptr_eq(Nr::base(&result1), &base));
+        assert!(Rc::ptr_eq(&result2, &base));
+    }
+
+    fn with_vec() {
+        // Act.
+        let result = Nr::<[i32]>::from(vec![1, 2]);
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_slice() {
+        // Act.
+        let result = Nr::<[i32]>::from(&[1, 2][..]);
+        // Assert.
+        assert_eq!(&*result, [1, 2]);
+    }
+
+    fn with_string() {
+        // Act.
+        let result = Nr::<str>::from(String::from("test"));
+        // Assert.
+        assert_eq!(&*result, "test");
+    }
+
+    fn with_str() {
+        // Act.
+        let result = Nr::<str>::from("test");
+        // Assert.
+        assert_eq!(&*result, "test");
+    }
+}
+
+#[test]
+fn from_iter() {
+    // Act.
+    let result = (1..=3).collect::<Nr<[i32]>>();
+    // Assert.
+    assert_eq!(&*result, [1, 2, 3]);
+    assert_eq!(result, Nr::clone(&result));
+}
+
 #[test]
 fn hash() {
     when_same();
@@ -190,6 +766,7 @@ fn cmp() {
 fn eq() {
     when_same();
     when_diff();
+    when_weak();
 
     fn when_same() {
         // Arrange.
@@ -210,12 +787,23 @@ fn eq() {
         // Assert.
         assert!(!result);
     }
+
+    fn when_weak() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::downgrade(&target1);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
 }
 
 #[test]
 fn partial_cmp() {
     when_same();
     when_diff();
+    when_weak();
 
     fn when_same() {
         // Arrange.
@@ -239,4 +827,14 @@ fn partial_cmp() {
         let expecteds = [Some(Ordering::Less), Some(Ordering::Greater)];
         assert_eq!(HashSet::from(results), HashSet::from(expecteds));
     }
+
+    fn when_weak() {
+        // Arrange.
+        let target1 = Nr::new(());
+        let target2 = Nr::downgrade(&target1);
+        // Act.
+        let result = target1.partial_cmp(&target2);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
 }
@@ -1,7 +1,10 @@
+use easy_node::nw_unsize;
 use easy_node::prelude::*;
+use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
@@ -36,6 +39,18 @@ fn from_base() {
     assert_eq!(result, Nw::from_base(weak.clone()));
 }
 
+#[test]
+fn into_base() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = Nr::downgrade(&nr);
+    // Act.
+    let result = target.into_base();
+    // Assert.
+    assert_eq!(result.as_ptr(), Nr::as_ptr(&nr));
+    assert_eq!(Nr::weak_count(&nr), 1);
+}
+
 #[test]
 fn as_ptr() {
     with_normal();
@@ -60,6 +75,186 @@ fn as_ptr() {
     }
 }
 
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+    with_dangling();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Nr::<dyn Any>::from(Rc::new(42) as Rc<dyn Any>);
+        let target = Nr::downgrade(&nr);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap().upgrade().map(|x| *x), Some(42));
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Nr::<dyn Any>::from(Rc::new(42) as Rc<dyn Any>);
+        let target = Nr::downgrade(&nr);
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        assert!(result.unwrap_err() == Nr::downgrade(&nr));
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let nr = Nr::<dyn Any>::from(Rc::new(42) as Rc<dyn Any>);
+        let target = Nr::downgrade(&nr);
+        drop(nr);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn key() {
+    with_normal();
+    with_dangling();
+
+    fn with_normal() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target = Nr::downgrade(&node);
+        // Act.
+        let result = target.key();
+        // Assert.
+        assert_eq!(result.addr(), target.as_ptr().cast());
+        assert_eq!(result, Nr::key(&node));
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target = Nr::downgrade(&node);
+        drop(node);
+        // Act.
+        let result = target.key();
+        // Assert.
+        assert_eq!(result.addr(), target.as_ptr().cast());
+    }
+}
+
+#[test]
+fn ptr_eq() {
+    with_empty();
+    with_same();
+    with_diff();
+    with_unsized();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = Nw::<()>::new();
+        let target2 = Nw::<i32>::new();
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_same() {
+        // Arrange.
+        let node = Nr::new(());
+        let target1 = Nr::downgrade(&node);
+        let target2 = Nr::downgrade(&node);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let node1 = Nr::new(());
+        let node2 = Nr::new(());
+        let target1 = Nr::downgrade(&node1);
+        let target2 = Nr::downgrade(&node2);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn with_unsized() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target1 = nw_unsize!(Nr::downgrade(&node), dyn Any);
+        let target2 = nw_unsize!(Nr::downgrade(&node), dyn Debug);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_cmp() {
+    // Arrange.
+    let node1 = Nr::new(1);
+    let node2 = Nr::new(2);
+    let target1 = nw_unsize!(Nr::downgrade(&node1), dyn Any);
+    let target2 = nw_unsize!(Nr::downgrade(&node2), dyn Debug);
+    // Act.
+    let result = target1.ptr_cmp(&target2);
+    // Assert.
+    assert_eq!(result, node1.cmp(&node2));
+}
+
+#[test]
+fn debug_graph() {
+    with_normal();
+    with_dangling();
+
+    fn with_normal() {
+        // Arrange.
+        let node = Nr::new(42);
+        let target = Nr::downgrade(&node);
+        // Act.
+        let result = format!("{:?}", target.debug_graph());
+        // Assert.
+        assert_eq!(&result, "#0 42");
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let target = Nw::<i32>::new();
+        // Act.
+        let result = format!("{:?}", target.debug_graph());
+        // Assert.
+        assert_eq!(&result, "Nw((Weak))");
+    }
+}
+
+#[test]
+fn into_raw() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = Nr::downgrade(&nr);
+    // Act.
+    let result = target.into_raw();
+    // Assert.
+    assert_eq!(result, Nr::as_ptr(&nr));
+    drop(unsafe { Nw::from_raw(result) });
+}
+
+#[test]
+fn from_raw() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let ptr = Nr::downgrade(&nr).into_raw();
+    // Act.
+    let result = unsafe { Nw::from_raw(ptr) };
+    // Assert.
+    assert_eq!(result, Nr::downgrade(&nr));
+    assert_eq!(Nr::weak_count(&nr), 1);
+}
+
 #[test]
 fn base() {
     // Arrange.
@@ -98,6 +293,7 @@ fn upgrade() {
         assert_eq!(result, None);
     }
 
+    #[allow(clippy::needless_borrow)]
     fn with_normal() {
         // Arrange.
         let nr = Nr::new(42);
@@ -154,6 +350,33 @@ fn default() {
     assert_eq!(result, Nw::new());
 }
 
+#[test]
+fn from() {
+    with_strong();
+    with_base();
+
+    fn with_strong() {
+        // Arrange.
+        let nr = Nr::<str>::from("test");
+        // Act.
+        let result = Nw::from(&nr);
+        // Assert.
+        assert_eq!(result, Nr::downgrade(&nr));
+    }
+
+    fn with_base() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let base = Nr::downgrade(&nr).base().clone();
+        // Act.
+        let result1 = Nw::from(base.clone());
+        let result2 = std::rc::Weak::from(result1.clone());
+        // Assert.
+        assert!(result1.base().ptr_eq(&base));
+        assert!(result2.ptr_eq(&base));
+    }
+}
+
 #[test]
 fn hash() {
     with_empty();
@@ -251,6 +474,7 @@ fn eq() {
     with_empty();
     with_same();
     with_diff();
+    with_strong();
 
     fn with_empty() {
         // Arrange.
@@ -284,6 +508,16 @@ fn eq() {
         // Assert.
         assert!(!result);
     }
+
+    fn with_strong() {
+        // Arrange.
+        let node = Nr::new(());
+        let target = Nr::downgrade(&node);
+        // Act.
+        let result = target.eq(&node);
+        // Assert.
+        assert!(result);
+    }
 }
 
 #[test]
@@ -291,6 +525,7 @@ fn partial_cmp() {
     with_empty();
     with_same();
     with_diff();
+    with_strong();
 
     fn with_empty() {
         // Arrange.
@@ -327,4 +562,14 @@ fn partial_cmp() {
         let expecteds = [Some(Ordering::Less), Some(Ordering::Greater)];
         assert_eq!(HashSet::from(results), HashSet::from(expecteds));
     }
+
+    fn with_strong() {
+        // Arrange.
+        let node = Nr::new(());
+        let target = Nr::downgrade(&node);
+        // Act.
+        let result = target.partial_cmp(&node);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
 }
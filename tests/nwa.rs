@@ -0,0 +1,574 @@
+use easy_node::nwa_unsize;
+use easy_node::prelude::*;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+#[test]
+fn new() {
+    // Act.
+    let result = NwA::<()>::new();
+    // Assert.
+    assert_eq!(result.strong_count(), 0);
+    assert_eq!(result.weak_count(), 0);
+}
+
+#[test]
+fn as_base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    let weak = Arc::downgrade(&arc);
+    // Act.
+    let result = NwA::as_base(&weak);
+    // Assert.
+    assert_eq!(result, NwA::as_base(&weak));
+}
+
+#[test]
+fn from_base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    let weak = Arc::downgrade(&arc);
+    // Act.
+    let result = NwA::from_base(weak.clone());
+    // Assert.
+    assert_eq!(result, NwA::from_base(weak.clone()));
+}
+
+#[test]
+fn into_base() {
+    // Arrange.
+    let nr = Na::new(42);
+    let target = Na::downgrade(&nr);
+    // Act.
+    let result = target.into_base();
+    // Assert.
+    assert_eq!(result.as_ptr(), Na::as_ptr(&nr));
+    assert_eq!(Na::weak_count(&nr), 1);
+}
+
+#[test]
+fn as_ptr() {
+    with_normal();
+    with_dangling();
+
+    fn with_normal() {
+        // Arrange.
+        let nr = Na::new(42);
+        let target = Na::downgrade(&nr);
+        // Act.
+        let result = target.as_ptr();
+        // Assert.
+        assert_eq!(result, Na::as_ptr(&nr));
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let nr = Na::new(42);
+        let target = Na::downgrade(&nr);
+        // Act and assert.
+        let _dangling = target.as_ptr();
+    }
+}
+
+#[test]
+fn downcast() {
+    with_match();
+    with_mismatch();
+    with_dangling();
+
+    fn with_match() {
+        // Arrange.
+        let nr = Na::<dyn Any>::from(Arc::new(42) as Arc<dyn Any>);
+        let target = Na::downgrade(&nr);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert_eq!(result.unwrap().upgrade().map(|x| *x), Some(42));
+    }
+
+    fn with_mismatch() {
+        // Arrange.
+        let nr = Na::<dyn Any>::from(Arc::new(42) as Arc<dyn Any>);
+        let target = Na::downgrade(&nr);
+        // Act.
+        let result = target.downcast::<u32>();
+        // Assert.
+        assert!(result.unwrap_err() == Na::downgrade(&nr));
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let nr = Na::<dyn Any>::from(Arc::new(42) as Arc<dyn Any>);
+        let target = Na::downgrade(&nr);
+        drop(nr);
+        // Act.
+        let result = target.downcast::<i32>();
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn key() {
+    with_normal();
+    with_dangling();
+
+    fn with_normal() {
+        // Arrange.
+        let node = Na::new(42);
+        let target = Na::downgrade(&node);
+        // Act.
+        let result = target.key();
+        // Assert.
+        assert_eq!(result.addr(), target.as_ptr().cast());
+        assert_eq!(result, Na::key(&node));
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let node = Na::new(42);
+        let target = Na::downgrade(&node);
+        drop(node);
+        // Act.
+        let result = target.key();
+        // Assert.
+        assert_eq!(result.addr(), target.as_ptr().cast());
+    }
+}
+
+#[test]
+fn ptr_eq() {
+    with_empty();
+    with_same();
+    with_diff();
+    with_unsized();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = NwA::<()>::new();
+        let target2 = NwA::<i32>::new();
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_same() {
+        // Arrange.
+        let node = Na::new(());
+        let target1 = Na::downgrade(&node);
+        let target2 = Na::downgrade(&node);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let node1 = Na::new(());
+        let node2 = Na::new(());
+        let target1 = Na::downgrade(&node1);
+        let target2 = Na::downgrade(&node2);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn with_unsized() {
+        // Arrange.
+        let node = Na::new(42);
+        let target1 = nwa_unsize!(Na::downgrade(&node), dyn Any);
+        let target2 = nwa_unsize!(Na::downgrade(&node), dyn Debug);
+        // Act.
+        let result = target1.ptr_eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn ptr_cmp() {
+    // Arrange.
+    let node1 = Na::new(1);
+    let node2 = Na::new(2);
+    let target1 = nwa_unsize!(Na::downgrade(&node1), dyn Any);
+    let target2 = nwa_unsize!(Na::downgrade(&node2), dyn Debug);
+    // Act.
+    let result = target1.ptr_cmp(&target2);
+    // Assert.
+    assert_eq!(result, node1.cmp(&node2));
+}
+
+#[test]
+fn debug_graph() {
+    with_normal();
+    with_dangling();
+
+    fn with_normal() {
+        // Arrange.
+        let node = Na::new(42);
+        let target = Na::downgrade(&node);
+        // Act.
+        let result = format!("{:?}", target.debug_graph());
+        // Assert.
+        assert_eq!(&result, "#0 42");
+    }
+
+    fn with_dangling() {
+        // Arrange.
+        let target = NwA::<i32>::new();
+        // Act.
+        let result = format!("{:?}", target.debug_graph());
+        // Assert.
+        assert_eq!(&result, "NwA((Weak))");
+    }
+}
+
+#[test]
+fn into_raw() {
+    // Arrange.
+    let nr = Na::new(42);
+    let target = Na::downgrade(&nr);
+    // Act.
+    let result = target.into_raw();
+    // Assert.
+    assert_eq!(result, Na::as_ptr(&nr));
+    drop(unsafe { NwA::from_raw(result) });
+}
+
+#[test]
+fn from_raw() {
+    // Arrange.
+    let nr = Na::new(42);
+    let ptr = Na::downgrade(&nr).into_raw();
+    // Act.
+    let result = unsafe { NwA::from_raw(ptr) };
+    // Assert.
+    assert_eq!(result, Na::downgrade(&nr));
+    assert_eq!(Na::weak_count(&nr), 1);
+}
+
+#[test]
+fn base() {
+    // Arrange.
+    let arc = Arc::new(42);
+    let weak = Arc::downgrade(&arc);
+    let target = NwA::from_base(weak.clone());
+    // Act.
+    let result = target.base();
+    // Assert.
+    assert!(result.ptr_eq(&weak));
+}
+
+#[test]
+fn upgrade() {
+    with_empty();
+    with_droped_nr();
+    with_normal();
+
+    fn with_empty() {
+        // Arrange.
+        let target = NwA::<()>::new();
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, None);
+    }
+
+    fn with_droped_nr() {
+        // Arragne.
+        let nr = Na::new(42);
+        let target = Na::downgrade(&nr);
+        std::mem::drop(nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let nr = Na::new(42);
+        let target = Na::downgrade(&nr);
+        // Act.
+        let result = target.upgrade();
+        drop(nr);
+        // Assert.
+        assert_eq!(NwA::strong_count(&target), 1);
+        assert_eq!(Na::strong_count(result.as_ref().unwrap()), 1);
+        assert_eq!(NwA::weak_count(&target), 1);
+        assert_eq!(Na::weak_count(result.as_ref().unwrap()), 1);
+    }
+}
+
+#[test]
+fn clone() {
+    with_empty();
+    with_normal();
+
+    fn with_empty() {
+        // Arrange.
+        let target = NwA::<()>::new();
+        // Act.
+        let result = NwA::clone(&target);
+        // Assert.
+        assert_eq!(NwA::strong_count(&target), 0);
+        assert_eq!(NwA::strong_count(&result), 0);
+        assert_eq!(NwA::weak_count(&target), 0);
+        assert_eq!(NwA::weak_count(&result), 0);
+        assert_eq!(&result, &target);
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let nr = Na::new(());
+        let target = Na::downgrade(&nr);
+        // Act.
+        let result = NwA::clone(&target);
+        // Assert.
+        assert_eq!(Na::strong_count(&nr), 1);
+        assert_eq!(NwA::strong_count(&target), 1);
+        assert_eq!(NwA::strong_count(&result), 1);
+        assert_eq!(Na::weak_count(&nr), 2);
+        assert_eq!(NwA::weak_count(&target), 2);
+        assert_eq!(NwA::weak_count(&result), 2);
+        assert_eq!(&result, &target);
+    }
+}
+
+#[test]
+fn default() {
+    let result = <NwA<()> as Default>::default();
+    assert_eq!(result, NwA::new());
+}
+
+#[test]
+fn from() {
+    with_strong();
+    with_base();
+
+    fn with_strong() {
+        // Arrange.
+        let nr = Na::<str>::from("test");
+        // Act.
+        let result = NwA::from(&nr);
+        // Assert.
+        assert_eq!(result, Na::downgrade(&nr));
+    }
+
+    fn with_base() {
+        // Arrange.
+        let nr = Na::new(42);
+        let base = Na::downgrade(&nr).base().clone();
+        // Act.
+        let result1 = NwA::from(base.clone());
+        let result2 = std::sync::Weak::from(result1.clone());
+        // Assert.
+        assert!(result1.base().ptr_eq(&base));
+        assert!(result2.ptr_eq(&base));
+    }
+}
+
+#[test]
+fn hash() {
+    with_empty();
+    with_same();
+    with_diff();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = NwA::<()>::new();
+        let target2 = NwA::<()>::new();
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+        // Act.
+        target1.hash(&mut hasher1);
+        target2.hash(&mut hasher2);
+        // Assert.
+        assert_eq!(hasher1.finish(), hasher2.finish());
+    }
+
+    fn with_same() {
+        // Arrange.
+        let nr = Na::new(());
+        let target1 = Na::downgrade(&nr);
+        let target2 = Na::downgrade(&nr);
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+        // Act.
+        target1.hash(&mut hasher1);
+        target2.hash(&mut hasher2);
+        // Assert.
+        assert_eq!(hasher1.finish(), hasher2.finish());
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let rc1 = Na::new(());
+        let rc2 = Na::new(());
+        let target1 = Na::downgrade(&rc1);
+        let target2 = Na::downgrade(&rc2);
+        let mut hasher1 = DefaultHasher::new();
+        let mut hasher2 = DefaultHasher::new();
+        // Act.
+        target1.hash(&mut hasher1);
+        target2.hash(&mut hasher2);
+        // Assert.
+        assert_ne!(hasher1.finish(), hasher2.finish());
+    }
+}
+
+#[test]
+fn cmp() {
+    with_empty();
+    with_same();
+    with_diff();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = NwA::<()>::new();
+        let target2 = NwA::<()>::new();
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    fn with_same() {
+        // Arrange.
+        let nr = Na::new(());
+        let target1 = Na::downgrade(&nr);
+        let target2 = Na::downgrade(&nr);
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let rc1 = Na::new(());
+        let rc2 = Na::new(());
+        let target1 = Na::downgrade(&rc1);
+        let target2 = Na::downgrade(&rc2);
+        // Act.
+        let result1 = target1.cmp(&target2);
+        let result2 = target2.cmp(&target1);
+        let results = [result1, result2];
+        // Assert.
+        let expecteds = [Ordering::Less, Ordering::Greater];
+        assert_eq!(HashSet::from(results), HashSet::from(expecteds));
+    }
+}
+
+#[test]
+fn eq() {
+    with_empty();
+    with_same();
+    with_diff();
+    with_strong();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = NwA::<()>::new();
+        let target2 = NwA::<()>::new();
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_same() {
+        // Arrange.
+        let nr = Na::new(());
+        let target1 = Na::downgrade(&nr);
+        let target2 = Na::downgrade(&nr);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let rc1 = Na::new(());
+        let rc2 = Na::new(());
+        let target1 = Na::downgrade(&rc1);
+        let target2 = Na::downgrade(&rc2);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+
+    fn with_strong() {
+        // Arrange.
+        let node = Na::new(());
+        let target = Na::downgrade(&node);
+        // Act.
+        let result = target.eq(&node);
+        // Assert.
+        assert!(result);
+    }
+}
+
+#[test]
+fn partial_cmp() {
+    with_empty();
+    with_same();
+    with_diff();
+    with_strong();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = NwA::<()>::new();
+        let target2 = NwA::<()>::new();
+        // Act.
+        let result = target1.partial_cmp(&target2);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
+
+    fn with_same() {
+        // Arrange.
+        let nr = Na::new(());
+        let target1 = Na::downgrade(&nr);
+        let target2 = Na::downgrade(&nr);
+        // Act.
+        let result = target1.partial_cmp(&target2);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let rc1 = Na::new(());
+        let rc2 = Na::new(());
+        let target1 = Na::downgrade(&rc1);
+        let target2 = Na::downgrade(&rc2);
+        // Act.
+        let result1 = target1.partial_cmp(&target2);
+        let result2 = target2.partial_cmp(&target1);
+        let results = [result1, result2];
+        // Assert.
+        let expecteds = [Some(Ordering::Less), Some(Ordering::Greater)];
+        assert_eq!(HashSet::from(results), HashSet::from(expecteds));
+    }
+
+    fn with_strong() {
+        // Arrange.
+        let node = Na::new(());
+        let target = Na::downgrade(&node);
+        // Act.
+        let result = target.partial_cmp(&node);
+        // Assert.
+        assert_eq!(result, Some(Ordering::Equal));
+    }
+}
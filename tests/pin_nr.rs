@@ -0,0 +1,163 @@
+use easy_node::PinNr;
+use easy_node::prelude::*;
+use std::collections::HashSet;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+#[test]
+fn new() {
+    // Arrange.
+    let nr = Nr::new(42);
+    // Act.
+    let result = PinNr::new(nr.clone());
+    // Assert.
+    assert_eq!(PinNr::key(&result), Nr::key(&nr));
+}
+
+#[test]
+fn into_nr() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = PinNr::new(nr.clone());
+    // Act.
+    let result = PinNr::into_nr(target);
+    // Assert.
+    assert_eq!(result, nr);
+}
+
+#[test]
+fn new_unchecked() {
+    // Arrange.
+    let nr = Nr::new((42, PhantomPinned));
+    // Act.
+    let result = unsafe { PinNr::new_unchecked(nr.clone()) };
+    // Assert.
+    assert_eq!(PinNr::key(&result), Nr::key(&nr));
+}
+
+#[test]
+fn as_pin() {
+    // Arrange.
+    let target = Nr::pin((42, PhantomPinned));
+    // Act.
+    let result = PinNr::as_pin(&target);
+    // Assert.
+    assert_eq!(result.0, 42);
+    assert_eq!(&*result as *const _, PinNr::as_ptr(&target));
+}
+
+#[test]
+fn into_pin() {
+    // Arrange.
+    let target = Nr::pin((42, PhantomPinned));
+    let ptr = PinNr::as_ptr(&target);
+    // Act.
+    let result = PinNr::into_pin(target);
+    // Assert.
+    assert_eq!(&*result as *const _, ptr);
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let target = Nr::pin((42, PhantomPinned));
+    // Act.
+    let result = PinNr::downgrade(&target);
+    // Assert.
+    assert_eq!(result.key(), PinNr::key(&target));
+    assert_eq!(PinNr::weak_count(&target), 1);
+}
+
+#[test]
+fn clone() {
+    // Arrange.
+    let target = Nr::pin((42, PhantomPinned));
+    // Act.
+    let result = target.clone();
+    // Assert.
+    assert_eq!(result, target);
+    assert_eq!(PinNr::strong_count(&target), 2);
+}
+
+#[test]
+fn fmt() {
+    // Arrange.
+    let target = Nr::pin(42);
+    // Act.
+    let result = format!("{target}");
+    // Assert.
+    assert_eq!(result, "42");
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = Nr::pin(42);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, "PinNr(42)");
+}
+
+#[test]
+fn from() {
+    // Arrange.
+    let target = Nr::pin((42, PhantomPinned));
+    let ptr = PinNr::as_ptr(&target);
+    // Act.
+    let result = Pin::<Nr<_>>::from(target);
+    // Assert.
+    assert_eq!(&*result as *const _, ptr);
+}
+
+#[test]
+fn hash() {
+    // Arrange.
+    let nr1 = Nr::pin(42);
+    let nr2 = Nr::pin(42);
+    let mut target = HashSet::new();
+    // Act.
+    target.insert(nr1.clone());
+    target.insert(nr1.clone());
+    target.insert(nr2.clone());
+    // Assert.
+    assert_eq!(target.len(), 2);
+    assert!(target.contains(PinNr::key(&nr1)));
+}
+
+#[test]
+fn eq() {
+    with_same();
+    with_diff();
+
+    fn with_same() {
+        // Arrange.
+        let target1 = Nr::pin(42);
+        let target2 = target1.clone();
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let target1 = Nr::pin(42);
+        let target2 = Nr::pin(42);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+}
+
+#[test]
+fn cmp() {
+    // Arrange.
+    let target1 = Nr::pin(42);
+    let target2 = Nr::pin(42);
+    // Act.
+    let result = target1.cmp(&target2);
+    // Assert.
+    assert_eq!(result, PinNr::key(&target1).cmp(PinNr::key(&target2)));
+}
@@ -0,0 +1,125 @@
+use easy_node::PinNr;
+use easy_node::prelude::*;
+use std::collections::HashSet;
+use std::marker::PhantomPinned;
+
+#[test]
+fn as_ptr() {
+    // Arrange.
+    let nr = Nr::pin((42, PhantomPinned));
+    let target = PinNr::downgrade(&nr);
+    // Act.
+    let result = target.as_ptr();
+    // Assert.
+    assert_eq!(result, PinNr::as_ptr(&nr));
+}
+
+#[test]
+fn upgrade() {
+    with_alive();
+    with_dead();
+
+    fn with_alive() {
+        // Arrange.
+        let nr = Nr::pin((42, PhantomPinned));
+        let target = PinNr::downgrade(&nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, Some(nr));
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let nr = Nr::pin((42, PhantomPinned));
+        let target = PinNr::downgrade(&nr);
+        drop(nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert!(result.is_none());
+        assert_eq!(target.strong_count(), 0);
+    }
+}
+
+#[test]
+fn fmt_debug() {
+    with_default();
+    with_graph();
+
+    fn with_default() {
+        // Arrange.
+        let nr = Nr::pin(42);
+        let target = PinNr::downgrade(&nr);
+        // Act.
+        let result = format!("{target:?}");
+        // Assert.
+        assert_eq!(result, "PinNw((Weak))");
+    }
+
+    fn with_graph() {
+        // Arrange.
+        let nr = Nr::pin(42);
+        let target = [PinNr::downgrade(&nr)];
+        // Act.
+        let result = format!("{:?}", Nr::debug_graph(&Nr::new(target)));
+        // Assert.
+        assert_eq!(result, "#0 [#1 42]");
+    }
+}
+
+#[test]
+fn hash() {
+    // Arrange.
+    let nr = Nr::pin(42);
+    let mut target = HashSet::new();
+    // Act.
+    target.insert(PinNr::downgrade(&nr));
+    target.insert(PinNr::downgrade(&nr));
+    // Assert.
+    assert_eq!(target.len(), 1);
+    assert!(target.contains(PinNr::key(&nr)));
+}
+
+#[test]
+fn eq() {
+    with_same();
+    with_diff();
+
+    fn with_same() {
+        // Arrange.
+        let nr = Nr::pin(42);
+        let target1 = PinNr::downgrade(&nr);
+        let target2 = PinNr::downgrade(&nr);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(result);
+        assert_eq!(target1.weak_count(), 2);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let nr1 = Nr::pin(42);
+        let nr2 = Nr::pin(42);
+        let target1 = PinNr::downgrade(&nr1);
+        let target2 = PinNr::downgrade(&nr2);
+        // Act.
+        let result = target1.eq(&target2);
+        // Assert.
+        assert!(!result);
+    }
+}
+
+#[test]
+fn cmp() {
+    // Arrange.
+    let nr1 = Nr::pin(42);
+    let nr2 = Nr::pin(42);
+    let target1 = PinNr::downgrade(&nr1);
+    let target2 = PinNr::downgrade(&nr2);
+    // Act.
+    let result = target1.cmp(&target2);
+    // Assert.
+    assert_eq!(result, nr1.cmp(&nr2));
+}
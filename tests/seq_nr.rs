@@ -0,0 +1,144 @@
+use easy_node::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[test]
+fn new() {
+    // Act.
+    let result1 = SeqNr::new(1);
+    let result2 = SeqNr::new(2);
+    // Assert.
+    assert_eq!(*result1, 1);
+    assert_eq!(*result2, 2);
+    assert!(SeqNr::seq(&result1) < SeqNr::seq(&result2));
+    assert_eq!(SeqNr::strong_count(&result1), 1);
+    assert_eq!(SeqNr::weak_count(&result1), 0);
+}
+
+#[test]
+fn new_cyclic() {
+    // Act.
+    let result = SeqNr::new_cyclic(|w| Cyclic { me: w.clone() });
+    // Assert.
+    assert_eq!(result.me, SeqNr::downgrade(&result));
+    assert_eq!(result.me.seq(), SeqNr::seq(&result));
+    assert_eq!(SeqNr::strong_count(&result), 1);
+    assert_eq!(SeqNr::weak_count(&result), 1);
+
+    #[derive(Debug)]
+    struct Cyclic {
+        me: SeqNw<Self>,
+    }
+}
+
+#[test]
+fn as_ptr() {
+    // Arrange.
+    let target = SeqNr::new(42);
+    // Act.
+    let result = SeqNr::as_ptr(&target);
+    // Assert.
+    assert_eq!(result, Nr::as_ptr(SeqNr::base(&target)));
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let target = SeqNr::new(42);
+    // Act.
+    let result = SeqNr::downgrade(&target);
+    // Assert.
+    assert_eq!(result.seq(), SeqNr::seq(&target));
+    assert_eq!(SeqNr::weak_count(&target), 1);
+}
+
+#[test]
+fn clone() {
+    // Arrange.
+    let target = SeqNr::new(42);
+    // Act.
+    let result = SeqNr::clone(&target);
+    // Assert.
+    assert_eq!(SeqNr::seq(&result), SeqNr::seq(&target));
+    assert_eq!(SeqNr::strong_count(&target), 2);
+    assert_eq!(&result, &target);
+}
+
+#[test]
+fn fmt() {
+    // Arrange.
+    let target = SeqNr::new("test");
+    // Act.
+    let result = format!("{target}");
+    // Assert.
+    assert_eq!(&result, "test")
+}
+
+#[test]
+fn hash() {
+    // Arrange.
+    let target1 = SeqNr::new(());
+    let target2 = SeqNr::clone(&target1);
+    let mut hasher1 = DefaultHasher::new();
+    let mut hasher2 = DefaultHasher::new();
+    // Act.
+    target1.hash(&mut hasher1);
+    target2.hash(&mut hasher2);
+    // Assert.
+    assert_eq!(hasher1.finish(), hasher2.finish());
+}
+
+#[test]
+fn cmp() {
+    with_same();
+    with_diff();
+    with_btree_set();
+
+    fn with_same() {
+        // Arrange.
+        let target1 = SeqNr::new(());
+        let target2 = SeqNr::clone(&target1);
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let target1 = SeqNr::new(());
+        let target2 = SeqNr::new(());
+        // Act.
+        let result1 = target1.cmp(&target2);
+        let result2 = target2.cmp(&target1);
+        // Assert.
+        assert_eq!(result1, Ordering::Less);
+        assert_eq!(result2, Ordering::Greater);
+    }
+
+    fn with_btree_set() {
+        // Arrange.
+        let targets = (0..10).map(SeqNr::new).collect::<Vec<_>>();
+        // Act.
+        let result = targets.iter().rev().cloned().collect::<BTreeSet<_>>();
+        // Assert.
+        let values = result.iter().map(|x| **x).collect::<Vec<_>>();
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn eq() {
+    // Arrange.
+    let target1 = SeqNr::new(());
+    let target2 = SeqNr::clone(&target1);
+    let target3 = SeqNr::new(());
+    // Act.
+    let result1 = target1.eq(&target2);
+    let result2 = target1.eq(&target3);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
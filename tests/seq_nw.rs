@@ -0,0 +1,95 @@
+use easy_node::prelude::*;
+use std::cmp::Ordering;
+
+#[test]
+fn new() {
+    // Act.
+    let result = SeqNw::<()>::new();
+    // Assert.
+    assert_eq!(result.seq(), u64::MAX);
+    assert_eq!(result.strong_count(), 0);
+    assert_eq!(result.weak_count(), 0);
+}
+
+#[test]
+fn upgrade() {
+    with_empty();
+    with_dropped();
+    with_normal();
+
+    fn with_empty() {
+        // Arrange.
+        let target = SeqNw::<()>::new();
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, None);
+    }
+
+    fn with_dropped() {
+        // Arrange.
+        let nr = SeqNr::new(42);
+        let target = SeqNr::downgrade(&nr);
+        drop(nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let nr = SeqNr::new(42);
+        let target = SeqNr::downgrade(&nr);
+        // Act.
+        let result = target.upgrade();
+        // Assert.
+        assert_eq!(result, Some(nr.clone()));
+        assert_eq!(SeqNr::seq(&result.unwrap()), SeqNr::seq(&nr));
+    }
+}
+
+#[test]
+fn cmp() {
+    with_empty();
+    with_diff();
+
+    fn with_empty() {
+        // Arrange.
+        let target1 = SeqNw::<()>::new();
+        let target2 = SeqNw::<()>::new();
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Equal);
+    }
+
+    fn with_diff() {
+        // Arrange.
+        let nr1 = SeqNr::new(());
+        let nr2 = SeqNr::new(());
+        let target1 = SeqNr::downgrade(&nr1);
+        let target2 = SeqNr::downgrade(&nr2);
+        drop(nr1);
+        // Act.
+        let result = target1.cmp(&target2);
+        // Assert.
+        assert_eq!(result, Ordering::Less);
+    }
+}
+
+#[test]
+fn eq() {
+    // Arrange.
+    let nr1 = SeqNr::new(());
+    let nr2 = SeqNr::new(());
+    let target1 = SeqNr::downgrade(&nr1);
+    let target2 = SeqNr::downgrade(&nr1);
+    let target3 = SeqNr::downgrade(&nr2);
+    // Act.
+    let result1 = target1.eq(&target2);
+    let result2 = target1.eq(&target3);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
@@ -0,0 +1,225 @@
+#![cfg(feature = "serde")]
+
+use easy_node::SerdeGraph;
+use easy_node::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+#[test]
+fn serialize() {
+    with_single();
+    with_shared();
+    with_dead();
+    with_cycle();
+    with_no_scope();
+
+    fn with_single() {
+        // Arrange.
+        let target = Nr::new(42);
+        // Act.
+        let result = serde_json::to_string(&target).unwrap();
+        // Assert.
+        assert_eq!(result, r#"{"Def":[0,42]}"#);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = SerdeGraph((nr.clone(), Nr::downgrade(&nr)));
+        // Act.
+        let result = serde_json::to_string(&target).unwrap();
+        // Assert.
+        assert_eq!(result, r#"[{"Def":[0,42]},{"Ref":0}]"#);
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let target = Nw::<i32>::new();
+        // Act.
+        let result = serde_json::to_string(&target).unwrap();
+        // Assert.
+        assert_eq!(result, r#""Dead""#);
+    }
+
+    fn with_cycle() {
+        // Arrange.
+        let target = Nr::new_cyclic(|me| Cyclic {
+            value: 42,
+            me: me.clone(),
+        });
+        // Act.
+        let result = serde_json::to_string(&target).unwrap();
+        // Assert.
+        assert_eq!(result, r#"{"Def":[0,{"value":42,"me":{"Ref":0}}]}"#);
+    }
+
+    fn with_no_scope() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let target = (nr.clone(), nr);
+        // Act.
+        let result = serde_json::to_string(&target).unwrap();
+        // Assert.
+        assert_eq!(result, r#"[{"Def":[0,42]},{"Def":[0,42]}]"#);
+    }
+}
+
+#[test]
+fn deserialize() {
+    with_single();
+    with_shared();
+    with_dead();
+    with_cycle();
+    with_tree();
+    with_parent_link();
+    with_strong_cycle();
+    with_unknown_id();
+    with_invalid_value();
+    with_invalid_value_in_cycle();
+
+    fn with_single() {
+        // Arrange.
+        let json = r#"{"Def":[0,42]}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<i32>>(json).unwrap();
+        // Assert.
+        assert_eq!(*result, 42);
+        assert_eq!(Nr::strong_count(&result), 1);
+    }
+
+    fn with_shared() {
+        // Arrange.
+        let json = r#"[{"Def":[0,42]},{"Ref":0},{"Ref":0}]"#;
+        // Act.
+        let result = serde_json::from_str::<SerdeGraph<(Nr<i32>, Nr<i32>, Nw<i32>)>>(json);
+        // Assert.
+        let (x, y, z) = result.unwrap().0;
+        assert_eq!(x, y);
+        assert_eq!(Nr::downgrade(&x), z);
+        assert_eq!(Nr::strong_count(&x), 2);
+        assert_eq!(Nr::weak_count(&x), 1);
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let json = r#""Dead""#;
+        // Act.
+        let result1 = serde_json::from_str::<Nw<i32>>(json);
+        let result2 = serde_json::from_str::<Nr<i32>>(json);
+        // Assert.
+        assert_eq!(result1.unwrap(), Nw::new());
+        assert!(result2.is_err());
+    }
+
+    fn with_cycle() {
+        // Arrange.
+        let json = r#"{"Def":[0,{"value":42,"me":{"Ref":0}}]}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<Cyclic>>(json).unwrap();
+        // Assert.
+        assert_eq!(result.value, 42);
+        assert_eq!(result.me, Nr::downgrade(&result));
+        assert_eq!(Nr::strong_count(&result), 1);
+        assert_eq!(Nr::weak_count(&result), 1);
+    }
+
+    fn with_tree() {
+        // Arrange.
+        let parent = Nr::new_cyclic(|me| Tree {
+            parent: Nw::new(),
+            children: RefCell::new(vec![]),
+            me: me.clone(),
+        });
+        for _ in 0..2 {
+            let child = Nr::new_cyclic(|me| Tree {
+                parent: Nr::downgrade(&parent),
+                children: RefCell::new(vec![]),
+                me: me.clone(),
+            });
+            parent.children.borrow_mut().push(child);
+        }
+        let json = serde_json::to_string(&parent).unwrap();
+        // Act.
+        let result = serde_json::from_str::<Nr<Tree>>(&json).unwrap();
+        // Assert.
+        let children = result.children.borrow();
+        assert_eq!(children.len(), 2);
+        assert_ne!(children[0], children[1]);
+        assert_eq!(children[0].parent, Nr::downgrade(&result));
+        assert_eq!(children[1].parent, Nr::downgrade(&result));
+        assert_eq!(Nr::strong_count(&result), 1);
+        assert_eq!(Nr::weak_count(&result), 3);
+    }
+
+    fn with_parent_link() {
+        // Arrange.
+        let parent = Nr::new_cyclic(|w| Parent {
+            kids: vec![Nr::new(Kid { parent: w.clone() })],
+        });
+        let json = serde_json::to_string(&parent).unwrap();
+        // Act.
+        let result = serde_json::from_str::<Nr<Parent>>(&json).unwrap();
+        // Assert.
+        assert_eq!(result.kids.len(), 1);
+        assert_eq!(result.kids[0].parent, result);
+    }
+
+    fn with_strong_cycle() {
+        // Arrange.
+        let json = r#"{"Def":[0,[{"Ref":0}]]}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<Vec<Nr<()>>>>(json);
+        // Assert.
+        assert!(result.is_err());
+    }
+
+    fn with_unknown_id() {
+        // Arrange.
+        let json = r#"{"Ref":0}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<i32>>(json);
+        // Assert.
+        assert!(result.is_err());
+    }
+
+    fn with_invalid_value() {
+        // Arrange.
+        let json = r#"{"Def":[0,"text"]}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<i32>>(json);
+        // Assert.
+        assert!(result.is_err());
+    }
+
+    fn with_invalid_value_in_cycle() {
+        // Arrange.
+        let json = r#"{"Def":[0,{"value":"text","me":{"Ref":0}}]}"#;
+        // Act.
+        let result = serde_json::from_str::<Nr<Cyclic>>(json);
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Cyclic {
+    value: i32,
+    me: Nw<Cyclic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Tree {
+    parent: Nw<Tree>,
+    children: RefCell<Vec<Nr<Tree>>>,
+    me: Nw<Tree>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Parent {
+    kids: Vec<Nr<Kid>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Kid {
+    parent: Nw<Parent>,
+}
@@ -0,0 +1,356 @@
+use easy_node::TreeNode;
+use easy_node::prelude::*;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn new() {
+    // Act.
+    let result = TreeNode::new(42);
+    // Assert.
+    assert_eq!(*result.value(), 42);
+    assert!(result.parent().is_none());
+    assert_eq!(result.children().count(), 0);
+}
+
+#[test]
+fn parent() {
+    with_root();
+    with_child();
+    with_dropped_parent();
+
+    fn with_root() {
+        // Arrange.
+        let target = node(0);
+        // Act.
+        let result = target.parent();
+        // Assert.
+        assert!(result.is_none());
+    }
+
+    fn with_child() {
+        // Arrange.
+        let root = node(0);
+        let target = node(1);
+        TreeNode::append_child(&root, target.clone());
+        // Act.
+        let result = target.parent();
+        // Assert.
+        assert_eq!(result, Some(root));
+    }
+
+    fn with_dropped_parent() {
+        // Arrange.
+        let root = node(0);
+        let target = node(1);
+        TreeNode::append_child(&root, target.clone());
+        drop(root);
+        // Act.
+        let result = target.parent();
+        // Assert.
+        assert!(result.is_none());
+    }
+}
+
+#[test]
+fn children() {
+    with_forward();
+    with_backward();
+    with_detach();
+
+    fn with_forward() {
+        // Arrange.
+        let target = tree();
+        // Act.
+        let result = target.children();
+        // Assert.
+        assert_eq!(values(result), [1, 4]);
+    }
+
+    fn with_backward() {
+        // Arrange.
+        let target = tree();
+        // Act.
+        let result = target.children().rev();
+        // Assert.
+        assert_eq!(values(result), [4, 1]);
+    }
+
+    fn with_detach() {
+        // Arrange.
+        let target = node(0);
+        (1..5).for_each(|i| TreeNode::append_child(&target, node(i)));
+        // Act.
+        let result = target.children().inspect(|x| x.detach());
+        // Assert.
+        assert_eq!(values(result), [1, 2, 3, 4]);
+        assert_eq!(target.children().len(), 0);
+    }
+}
+
+#[test]
+fn ancestors() {
+    // Arrange.
+    let root = tree();
+    let target = TreeNode::descendants(&root).find(|x| *x.value() == 3);
+    // Act.
+    let result = target.unwrap().ancestors();
+    // Assert.
+    assert_eq!(values(result), [1, 0]);
+}
+
+#[test]
+fn root() {
+    with_root();
+    with_leaf();
+
+    fn with_root() {
+        // Arrange.
+        let target = tree();
+        // Act.
+        let result = TreeNode::root(&target);
+        // Assert.
+        assert_eq!(result, target);
+    }
+
+    fn with_leaf() {
+        // Arrange.
+        let root = tree();
+        let target = TreeNode::descendants(&root).last().unwrap();
+        // Act.
+        let result = TreeNode::root(&target);
+        // Assert.
+        assert_eq!(result, root);
+    }
+}
+
+#[test]
+fn descendants() {
+    // Arrange.
+    let target = tree();
+    // Act.
+    let result = TreeNode::descendants(&target);
+    // Assert.
+    assert_eq!(values(result), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn descendants_post_order() {
+    // Arrange.
+    let target = tree();
+    // Act.
+    let result = TreeNode::descendants_post_order(&target);
+    // Assert.
+    assert_eq!(values(result), [2, 3, 1, 4, 0]);
+}
+
+#[test]
+fn append_child() {
+    with_new();
+    with_moved();
+    with_self();
+    with_ancestor();
+
+    fn with_new() {
+        // Arrange.
+        let target = node(0);
+        let child = node(1);
+        // Act.
+        TreeNode::append_child(&target, child.clone());
+        // Assert.
+        assert_eq!(values(target.children()), [1]);
+        assert_eq!(child.parent(), Some(target));
+    }
+
+    fn with_moved() {
+        // Arrange.
+        let target = node(0);
+        let old_parent = node(1);
+        let child = node(2);
+        TreeNode::append_child(&old_parent, child.clone());
+        // Act.
+        TreeNode::append_child(&target, child.clone());
+        // Assert.
+        assert_eq!(values(target.children()), [2]);
+        assert_eq!(values(old_parent.children()), []);
+        assert_eq!(child.parent(), Some(target));
+    }
+
+    fn with_self() {
+        // Arrange.
+        let target = node(0);
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            TreeNode::append_child(&target, target.clone());
+        }));
+        // Assert.
+        assert!(result.is_err());
+    }
+
+    fn with_ancestor() {
+        // Arrange.
+        let root = tree();
+        let target = TreeNode::descendants(&root).last().unwrap();
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            TreeNode::append_child(&target, root.clone());
+        }));
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn insert_before() {
+    with_new();
+    with_sibling();
+    with_reference();
+    with_non_child();
+
+    fn with_new() {
+        // Arrange.
+        let target = tree();
+        let reference = target.children().next_back().unwrap();
+        let child = node(5);
+        // Act.
+        TreeNode::insert_before(&target, child.clone(), &reference);
+        // Assert.
+        assert_eq!(values(target.children()), [1, 5, 4]);
+        assert_eq!(child.parent(), Some(target));
+    }
+
+    fn with_sibling() {
+        // Arrange.
+        let target = tree();
+        let reference = target.children().next().unwrap();
+        let child = target.children().next_back().unwrap();
+        // Act.
+        TreeNode::insert_before(&target, child, &reference);
+        // Assert.
+        assert_eq!(values(target.children()), [4, 1]);
+    }
+
+    fn with_reference() {
+        // Arrange.
+        let target = tree();
+        let reference = target.children().next().unwrap();
+        // Act.
+        TreeNode::insert_before(&target, reference.clone(), &reference);
+        // Assert.
+        assert_eq!(values(target.children()), [1, 4]);
+    }
+
+    fn with_non_child() {
+        // Arrange.
+        let target = tree();
+        let reference = node(5);
+        // Act.
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            TreeNode::insert_before(&target, node(6), &reference);
+        }));
+        // Assert.
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn detach() {
+    with_child();
+    with_root();
+
+    fn with_child() {
+        // Arrange.
+        let root = tree();
+        let target = root.children().next().unwrap();
+        // Act.
+        target.detach();
+        // Assert.
+        assert!(target.parent().is_none());
+        assert_eq!(values(root.children()), [4]);
+        assert_eq!(values(TreeNode::descendants(&target)), [1, 2, 3]);
+    }
+
+    fn with_root() {
+        // Arrange.
+        let target = tree();
+        // Act.
+        target.detach();
+        // Assert.
+        assert!(target.parent().is_none());
+        assert_eq!(values(target.children()), [1, 4]);
+    }
+}
+
+#[test]
+fn neighbors() {
+    // Arrange.
+    let root = tree();
+    let target = root.children().next().unwrap();
+    // Act.
+    let result = target.neighbors().collect::<Vec<_>>();
+    // Assert.
+    assert!(result.iter().all(Link::is_strong));
+    assert_eq!(values(result.iter().filter_map(Link::upgrade)), [2, 3]);
+}
+
+#[test]
+fn drop_shared() {
+    // Arrange.
+    let root = tree();
+    let target = root.children().next().unwrap();
+    // Act.
+    std::mem::drop(root);
+    // Assert.
+    assert!(target.parent().is_none());
+    assert_eq!(values(TreeNode::descendants(&target)), [1, 2, 3]);
+}
+
+#[test]
+fn drop_deep() {
+    // Arrange.
+    let leaf = node(0);
+    let weak = Nr::downgrade(&leaf);
+    let root = (1..1_000_000).fold(leaf, |child, i| {
+        let parent = node(i);
+        TreeNode::append_child(&parent, child);
+        parent
+    });
+    // Act.
+    std::mem::drop(root);
+    // Assert.
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = node(0);
+    TreeNode::append_child(&target, node(1));
+    // Act.
+    let result = format!("{:?}", *target);
+    // Assert.
+    let expected = "TreeNode { value: 0, children: [Nr(TreeNode { value: 1, children: [] })] }";
+    assert_eq!(result, expected);
+}
+
+/// Creates a new node.
+fn node(value: i32) -> Nr<TreeNode<i32>> {
+    Nr::new(TreeNode::new(value))
+}
+
+/// Creates tree (`0` has `1` and `4`, and `1` has `2` and `3`).
+fn tree() -> Nr<TreeNode<i32>> {
+    let nodes = (0..5).map(node).collect::<Vec<_>>();
+    TreeNode::append_child(&nodes[0], nodes[1].clone());
+    TreeNode::append_child(&nodes[1], nodes[2].clone());
+    TreeNode::append_child(&nodes[1], nodes[3].clone());
+    TreeNode::append_child(&nodes[0], nodes[4].clone());
+    nodes[0].clone()
+}
+
+/// Returns values of nodes.
+fn values<I>(nodes: I) -> Vec<i32>
+where
+    I: IntoIterator<Item = Nr<TreeNode<i32>>>,
+{
+    nodes.into_iter().map(|x| *x.value()).collect()
+}
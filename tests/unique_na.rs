@@ -0,0 +1,77 @@
+#![cfg(feature = "nightly")]
+
+use easy_node::UniqueNa;
+use easy_node::prelude::*;
+
+#[test]
+fn new() {
+    // Act.
+    let result = UniqueNa::new(42);
+    // Assert.
+    assert_eq!(*result, 42);
+}
+
+#[test]
+fn as_ptr() {
+    // Arrange.
+    let target = UniqueNa::new(42);
+    // Act.
+    let result = UniqueNa::as_ptr(&target);
+    // Assert.
+    assert_eq!(result, &*target as *const _);
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let target = UniqueNa::new(42);
+    // Act.
+    let result = UniqueNa::key(&target);
+    // Assert.
+    assert_eq!(result.addr(), UniqueNa::as_ptr(&target).cast());
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let target = UniqueNa::new(42);
+    // Act.
+    let result = UniqueNa::downgrade(&target);
+    // Assert.
+    assert_eq!(result.key(), UniqueNa::key(&target));
+    assert!(result.upgrade().is_none());
+}
+
+#[test]
+fn into_na() {
+    // Arrange.
+    let mut target = UniqueNa::new(Node {
+        value: 1,
+        me: NwA::new(),
+    });
+    let nw = UniqueNa::downgrade(&target);
+    target.value += 1;
+    target.me = nw.clone();
+    // Act.
+    let result = UniqueNa::into_na(target);
+    // Assert.
+    assert_eq!(result.value, 2);
+    assert_eq!(result.me, result);
+    assert_eq!(nw.upgrade(), Some(result));
+
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        me: NwA<Node>,
+    }
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = UniqueNa::new(42);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, "UniqueNa(42)");
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "nightly")]
+
+use easy_node::UniqueNr;
+use easy_node::prelude::*;
+
+#[test]
+fn new() {
+    // Act.
+    let result = UniqueNr::new(42);
+    // Assert.
+    assert_eq!(*result, 42);
+}
+
+#[test]
+fn as_ptr() {
+    // Arrange.
+    let target = UniqueNr::new(42);
+    // Act.
+    let result = UniqueNr::as_ptr(&target);
+    // Assert.
+    assert_eq!(result, &*target as *const _);
+}
+
+#[test]
+fn key() {
+    // Arrange.
+    let target = UniqueNr::new(42);
+    // Act.
+    let result = UniqueNr::key(&target);
+    // Assert.
+    assert_eq!(result.addr(), UniqueNr::as_ptr(&target).cast());
+}
+
+#[test]
+fn downgrade() {
+    // Arrange.
+    let target = UniqueNr::new(42);
+    // Act.
+    let result = UniqueNr::downgrade(&target);
+    // Assert.
+    assert_eq!(result.key(), UniqueNr::key(&target));
+    assert!(result.upgrade().is_none());
+}
+
+#[test]
+fn into_nr() {
+    // Arrange.
+    let mut target = UniqueNr::new(Node {
+        value: 1,
+        me: Nw::new(),
+    });
+    let nw = UniqueNr::downgrade(&target);
+    target.value += 1;
+    target.me = nw.clone();
+    // Act.
+    let result = UniqueNr::into_nr(target);
+    // Assert.
+    assert_eq!(result.value, 2);
+    assert_eq!(result.me, result);
+    assert_eq!(nw.upgrade(), Some(result));
+
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        me: Nw<Node>,
+    }
+}
+
+#[test]
+fn fmt_debug() {
+    // Arrange.
+    let target = UniqueNr::new(42);
+    // Act.
+    let result = format!("{target:?}");
+    // Assert.
+    assert_eq!(result, "UniqueNr(42)");
+}
@@ -0,0 +1,64 @@
+use easy_node::prelude::*;
+use easy_node::{na_unsize, nr_unsize, nw_unsize, nwa_unsize};
+use std::fmt::Display;
+
+#[test]
+fn nr_unsize() {
+    // Arrange.
+    let target = Nr::new(42);
+    // Act.
+    let result = nr_unsize!(target.clone(), dyn Display);
+    // Assert.
+    assert_eq!(result.to_string(), "42");
+    assert_eq!(Nr::as_ptr(&result).cast(), Nr::as_ptr(&target));
+    assert_eq!(Nr::strong_count(&result), 2);
+}
+
+#[test]
+fn nw_unsize() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let target = Nr::downgrade(&nr);
+    // Act.
+    let result = nw_unsize!(target, dyn Display);
+    // Assert.
+    assert_eq!(result.upgrade().unwrap().to_string(), "42");
+    assert_eq!(result.as_ptr().cast(), Nr::as_ptr(&nr));
+}
+
+#[test]
+fn na_unsize() {
+    // Arrange.
+    let target = Na::new(42);
+    // Act.
+    let result = na_unsize!(target.clone(), dyn Display + Send + Sync);
+    // Assert.
+    assert_eq!(result.to_string(), "42");
+    assert_eq!(Na::as_ptr(&result).cast(), Na::as_ptr(&target));
+}
+
+#[test]
+fn nwa_unsize() {
+    // Arrange.
+    let na = Na::new(42);
+    let target = Na::downgrade(&na);
+    // Act.
+    let result = nwa_unsize!(target, dyn Display + Send + Sync);
+    // Assert.
+    assert_eq!(result.upgrade().unwrap().to_string(), "42");
+    assert_eq!(result.as_ptr().cast(), Na::as_ptr(&na));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn coerce_unsized() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let nw = Nr::downgrade(&nr);
+    // Act.
+    let result1: Nr<dyn Display> = nr.clone();
+    let result2: Nw<dyn Display> = nw;
+    // Assert.
+    assert_eq!(result1.to_string(), "42");
+    assert_eq!(result2.upgrade().unwrap().to_string(), "42");
+}
@@ -0,0 +1,141 @@
+use easy_node::WeakNodeSet;
+use easy_node::prelude::*;
+use std::collections::HashSet;
+
+#[test]
+fn new() {
+    // Act.
+    let result = WeakNodeSet::<()>::new();
+    // Assert.
+    assert_eq!(result.len(), 0);
+    assert!(result.is_empty());
+    assert_eq!(result.reclaimed(), 0);
+}
+
+#[test]
+fn len() {
+    // Arrange.
+    let nr1 = Nr::new(1);
+    let nr2 = Nr::new(2);
+    let mut target = WeakNodeSet::new();
+    target.insert(&nr1);
+    target.insert(&nr2);
+    drop(nr1);
+    // Act.
+    let result = target.len();
+    // Assert.
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn contains() {
+    // Arrange.
+    let nr1 = Nr::new(42);
+    let nr2 = Nr::new(42);
+    let mut target = WeakNodeSet::new();
+    target.insert(&nr1);
+    // Act.
+    let result1 = target.contains(&nr1);
+    let result2 = target.contains(&nr2);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+}
+
+#[test]
+fn insert() {
+    with_new();
+    with_dup();
+    with_dead();
+
+    fn with_new() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let mut target = WeakNodeSet::new();
+        // Act.
+        let result = target.insert(&nr);
+        // Assert.
+        assert!(result);
+        assert_eq!(target.len(), 1);
+        assert_eq!(Nr::weak_count(&nr), 1);
+    }
+
+    fn with_dup() {
+        // Arrange.
+        let nr = Nr::new(42);
+        let mut target = WeakNodeSet::new();
+        target.insert(&nr);
+        // Act.
+        let result = target.insert(&nr);
+        // Assert.
+        assert!(!result);
+        assert_eq!(target.len(), 1);
+    }
+
+    fn with_dead() {
+        // Arrange.
+        let nr1 = Nr::new(1);
+        let nr2 = Nr::new(2);
+        let mut target = WeakNodeSet::new();
+        target.insert(&nr1);
+        drop(nr1);
+        // Act.
+        target.insert(&nr2);
+        // Assert.
+        assert_eq!(target.reclaimed(), 1);
+        assert_eq!(target.purge(), 0);
+    }
+}
+
+#[test]
+fn remove() {
+    // Arrange.
+    let nr = Nr::new(42);
+    let mut target = WeakNodeSet::new();
+    target.insert(&nr);
+    // Act.
+    let result1 = target.remove(&nr);
+    let result2 = target.remove(&nr);
+    // Assert.
+    assert!(result1);
+    assert!(!result2);
+    assert_eq!(Nr::weak_count(&nr), 0);
+}
+
+#[test]
+fn purge() {
+    // Arrange.
+    let nr1 = Nr::new(1);
+    let nr2 = Nr::new(2);
+    let nr3 = Nr::new(3);
+    let mut target = WeakNodeSet::new();
+    target.insert(&nr1);
+    target.insert(&nr2);
+    target.insert(&nr3);
+    drop(nr1);
+    drop(nr2);
+    // Act.
+    let result = target.purge();
+    // Assert.
+    assert_eq!(result, 2);
+    assert_eq!(target.reclaimed(), 2);
+    assert_eq!(target.len(), 1);
+}
+
+#[test]
+fn iter() {
+    // Arrange.
+    let nr1 = Nr::new(1);
+    let nr2 = Nr::new(2);
+    let nr3 = Nr::new(3);
+    let mut target = WeakNodeSet::new();
+    target.insert(&nr1);
+    target.insert(&nr2);
+    target.insert(&nr3);
+    drop(nr2);
+    // Act.
+    let result = target.iter().collect::<HashSet<_>>();
+    // Assert.
+    assert_eq!(result, HashSet::from([nr1, nr3]));
+    assert_eq!(target.reclaimed(), 1);
+}